@@ -0,0 +1,365 @@
+//! Command-line tool for the token registry, keypairs and RPC settings are read from the Solana CLI config
+
+use std::{error::Error, fs, str::FromStr};
+
+use clap::{ArgGroup, Parser, Subcommand};
+use serde::Serialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+use token_registry::{
+    derivation::derive_central_state,
+    instruction::{self, init, register},
+    metaplex::Metadata,
+    state::{CentralState, TokenData},
+};
+use token_registry_client::RegistryClient;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[derive(Parser)]
+#[clap(name = "token-registry", about = "Manage and query the token registry")]
+struct Cli {
+    /// Address of the registry program
+    #[clap(long)]
+    program_id: Pubkey,
+    /// Solana CLI config file, defaults to the standard location
+    #[clap(long)]
+    config: Option<String>,
+    /// Overrides the RPC URL of the config
+    #[clap(long)]
+    url: Option<String>,
+    /// Overrides the keypair of the config
+    #[clap(long)]
+    keypair: Option<String>,
+    /// Simulate transactions instead of sending them
+    #[clap(long)]
+    dry_run: bool,
+    /// Print JSON instead of human-readable output
+    #[clap(long)]
+    json: bool,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the central state of the registry
+    Init,
+    /// Register a token, as an admin with `--signer-index` or as the mint authority
+    Register {
+        #[clap(long)]
+        ticker: String,
+        #[clap(long)]
+        mint: Pubkey,
+        #[clap(long)]
+        name: String,
+        #[clap(long)]
+        decimals: u8,
+        #[clap(long, default_value = "")]
+        website: String,
+        #[clap(long, default_value = "")]
+        logo_uri: String,
+        /// Index of the signer in the admin list
+        #[clap(long)]
+        signer_index: Option<usize>,
+        /// Update authority of the entry, admins only
+        #[clap(long)]
+        update_authority: Option<Pubkey>,
+        /// Account paying the registration fee, defaults to the keypair
+        #[clap(long)]
+        fee_source: Option<Pubkey>,
+        /// Cross-check the ticker with the Metaplex metadata of the mint
+        #[clap(long)]
+        metaplex: bool,
+    },
+    /// Look up a token by ticker or by mint
+    #[clap(group(ArgGroup::new("key").required(true).args(&["ticker", "mint"])))]
+    Lookup {
+        #[clap(long)]
+        ticker: Option<String>,
+        #[clap(long)]
+        mint: Option<Pubkey>,
+    },
+    /// List the registered tokens
+    List,
+    /// Export every record of the registry as JSON
+    Export {
+        /// Output file, defaults to stdout
+        #[clap(long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(Serialize)]
+struct TokenInfo {
+    record: String,
+    ticker: String,
+    name: String,
+    mint: String,
+    decimals: u8,
+    status: String,
+    tier: String,
+    website: String,
+    logo_uri: String,
+    token_program: String,
+    extension_flags: u16,
+    update_authority: Option<String>,
+    report_count: u32,
+    open_report_count: u32,
+}
+
+impl TokenInfo {
+    fn new(record: Pubkey, token_data: TokenData) -> Self {
+        TokenInfo {
+            record: record.to_string(),
+            ticker: token_data.ticker,
+            name: token_data.name,
+            mint: Pubkey::new_from_array(token_data.mint).to_string(),
+            decimals: token_data.decimals,
+            status: format!("{:?}", token_data.status),
+            tier: format!("{:?}", token_data.tier),
+            website: token_data.website,
+            logo_uri: token_data.logo_uri,
+            token_program: Pubkey::new_from_array(token_data.token_program).to_string(),
+            extension_flags: token_data.extension_flags,
+            update_authority: Some(token_data.update_authority)
+                .filter(|key| *key != [0; 32])
+                .map(|key| Pubkey::new_from_array(key).to_string()),
+            report_count: token_data.report_count,
+            open_report_count: token_data.open_report_count,
+        }
+    }
+
+    fn print(&self) {
+        println!("Ticker:           {}", self.ticker);
+        println!("Name:             {}", self.name);
+        println!("Mint:             {}", self.mint);
+        println!("Decimals:         {}", self.decimals);
+        println!("Status:           {}", self.status);
+        println!("Tier:             {}", self.tier);
+        println!("Website:          {}", self.website);
+        println!("Logo URI:         {}", self.logo_uri);
+        println!("Token program:    {}", self.token_program);
+        println!("Extension flags:  {:#06x}", self.extension_flags);
+        if let Some(update_authority) = &self.update_authority {
+            println!("Update authority: {}", update_authority);
+        }
+        println!(
+            "Reports:          {} ({} open)",
+            self.report_count, self.open_report_count
+        );
+        println!("Record:           {}", self.record);
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+async fn process_transaction(
+    rpc_client: &RpcClient,
+    instruction: Instruction,
+    signer: &Keypair,
+    dry_run: bool,
+    json: bool,
+) -> Result<()> {
+    let blockhash = rpc_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[signer],
+        blockhash,
+    );
+
+    if dry_run {
+        let result = rpc_client.simulate_transaction(&transaction).await?.value;
+        let logs = result.logs.unwrap_or_default();
+        if json {
+            return print_json(&serde_json::json!({
+                "error": result.err.map(|err| err.to_string()),
+                "logs": logs,
+            }));
+        }
+        for log in logs {
+            println!("{}", log);
+        }
+        match result.err {
+            Some(err) => println!("Simulation failed: {}", err),
+            None => println!("Simulation succeeded"),
+        }
+        return Ok(());
+    }
+
+    let signature = rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .await?;
+    if json {
+        print_json(&serde_json::json!({ "signature": signature.to_string() }))
+    } else {
+        println!("Signature: {}", signature);
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let config_file = cli
+        .config
+        .clone()
+        .or_else(|| solana_cli_config::CONFIG_FILE.clone())
+        .ok_or("Unable to locate the Solana CLI config file")?;
+    // The default config file may not exist yet, a config file given explicitly must load
+    let config = match solana_cli_config::Config::load(&config_file) {
+        Err(err) if cli.config.is_some() => {
+            return Err(format!("Unable to load the config {}: {}", config_file, err).into())
+        }
+        result => result.unwrap_or_default(),
+    };
+    let url = cli.url.clone().unwrap_or(config.json_rpc_url);
+    let keypair_path = cli.keypair.clone().unwrap_or(config.keypair_path);
+    let commitment = CommitmentConfig::from_str(&config.commitment)?;
+
+    let client = RegistryClient::new(
+        RpcClient::new_with_commitment(url, commitment),
+        cli.program_id,
+    );
+    let read_keypair = || {
+        read_keypair_file(&keypair_path)
+            .map_err(|err| format!("Unable to read keypair {}: {}", keypair_path, err))
+    };
+
+    match cli.command {
+        Command::Init => {
+            let keypair = read_keypair()?;
+            let (central_state, signer_nonce) = derive_central_state(&cli.program_id);
+            let instruction = init(
+                cli.program_id,
+                central_state,
+                keypair.pubkey(),
+                instruction::init::Params { signer_nonce },
+            );
+            process_transaction(
+                &client.rpc_client,
+                instruction,
+                &keypair,
+                cli.dry_run,
+                cli.json,
+            )
+            .await?;
+        }
+        Command::Register {
+            ticker,
+            mint,
+            name,
+            decimals,
+            website,
+            logo_uri,
+            signer_index,
+            update_authority,
+            fee_source,
+            metaplex,
+        } => {
+            let keypair = read_keypair()?;
+            let (central_state_key, _) = derive_central_state(&cli.program_id);
+            let central_state_data = client
+                .rpc_client
+                .get_account_data(&central_state_key)
+                .await?;
+            let central_state = CentralState::unpack_from_slice(&central_state_data)?;
+            let instruction = register(
+                cli.program_id,
+                keypair.pubkey(),
+                mint,
+                Pubkey::new_from_array(central_state.fee_mint),
+                fee_source.unwrap_or_else(|| keypair.pubkey()),
+                metaplex.then(|| Metadata::find_key(&mint).0),
+                instruction::register::Params {
+                    signer_index,
+                    ticker,
+                    name,
+                    mint: mint.to_string(),
+                    decimals,
+                    website,
+                    logo_uri,
+                    update_authority,
+                },
+            );
+            process_transaction(
+                &client.rpc_client,
+                instruction,
+                &keypair,
+                cli.dry_run,
+                cli.json,
+            )
+            .await?;
+        }
+        Command::Lookup { ticker, mint } => {
+            let mint = match (ticker, mint) {
+                (_, Some(mint)) => mint,
+                (Some(ticker), None) => client
+                    .resolve_ticker(&ticker)
+                    .await?
+                    .ok_or_else(|| format!("Ticker {} is not registered", ticker))?,
+                (None, None) => unreachable!(),
+            };
+            let token_data = client
+                .resolve_mint(&mint)
+                .await?
+                .ok_or_else(|| format!("Mint {} is not registered", mint))?;
+            let record = token_registry::derivation::derive_mint_account(&cli.program_id, &mint);
+            let token_info = TokenInfo::new(record, token_data);
+            if cli.json {
+                print_json(&token_info)?;
+            } else {
+                token_info.print();
+            }
+        }
+        Command::List => {
+            let mut tokens = client
+                .list_all()
+                .await?
+                .into_iter()
+                .map(|(record, token_data)| TokenInfo::new(record, token_data))
+                .collect::<Vec<_>>();
+            tokens.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+            if cli.json {
+                print_json(&tokens)?;
+            } else {
+                for token in tokens {
+                    println!(
+                        "{:<10} {:<44} {:<10} {:<10} {}",
+                        token.ticker, token.mint, token.tier, token.status, token.name
+                    );
+                }
+            }
+        }
+        Command::Export { output } => {
+            let tokens = client
+                .list_all()
+                .await?
+                .into_iter()
+                .map(|(record, token_data)| TokenInfo::new(record, token_data))
+                .collect::<Vec<_>>();
+            let export = serde_json::to_string_pretty(&tokens)?;
+            match output {
+                Some(path) => {
+                    fs::write(&path, export)?;
+                    if !cli.json {
+                        println!("Exported {} tokens to {}", tokens.len(), path);
+                    }
+                }
+                None => println!("{}", export),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,278 @@
+//! Async client for the token registry, built on the nonblocking `RpcClient`
+//!
+//! The RPC calls go through the `RegistryRpc` trait and the parsing helpers are independent from
+//! the RPC, so that the client can be used with the accounts of any source, such as the banks
+//! client of `solana-program-test`.
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use thiserror::Error;
+use token_registry::{
+    derivation::{
+        derive_central_state, derive_legacy_mint_account, derive_legacy_ticker_account,
+        derive_mint_account, derive_ticker_account,
+    },
+    error::TokenRegistryError,
+    instruction::{self, register, update_metadata},
+    state::{TickerRecord, TokenData, TokenRecord, TOKEN_TLD},
+};
+
+/// Maximum number of accounts of a `getMultipleAccounts` request
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Offset of the owner in the name record header, after the parent
+const OWNER_OFFSET: usize = 32;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Rpc(#[from] ClientError),
+    #[error(transparent)]
+    Registry(#[from] TokenRegistryError),
+    #[error("Invalid mint {0}")]
+    InvalidMint(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// RPC calls made by the client
+#[async_trait]
+pub trait RegistryRpc {
+    /// At most `MAX_MULTIPLE_ACCOUNTS` keys, the accounts are returned in the same order
+    async fn get_multiple_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Account>>>;
+    /// Accounts owned by `program_id` that match all the `filters`
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, Account)>>;
+    async fn get_latest_blockhash(&self) -> Result<Hash>;
+    async fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature>;
+}
+
+#[async_trait]
+impl RegistryRpc for RpcClient {
+    async fn get_multiple_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Account>>> {
+        Ok(RpcClient::get_multiple_accounts(self, keys).await?)
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, Account)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        Ok(self
+            .get_program_accounts_with_config(program_id, config)
+            .await?)
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash> {
+        Ok(RpcClient::get_latest_blockhash(self).await?)
+    }
+
+    async fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature> {
+        Ok(RpcClient::send_and_confirm_transaction(self, transaction).await?)
+    }
+}
+
+pub struct RegistryClient<R = RpcClient> {
+    pub rpc_client: R,
+    pub program_id: Pubkey,
+}
+
+impl<R: RegistryRpc> RegistryClient<R> {
+    pub fn new(rpc_client: R, program_id: Pubkey) -> Self {
+        RegistryClient {
+            rpc_client,
+            program_id,
+        }
+    }
+
+    /// Returns the mint of the ticker, `None` when the ticker is not registered.
+    /// Tickers that were not migrated yet are looked up at their legacy address
+    pub async fn resolve_ticker(&self, ticker: &str) -> Result<Option<Pubkey>> {
+        let keys = [
+            derive_ticker_account(&self.program_id, ticker),
+            derive_legacy_ticker_account(ticker),
+        ];
+        let accounts = self.rpc_client.get_multiple_accounts(&keys).await?;
+        accounts
+            .into_iter()
+            .flatten()
+            .next()
+            .map(|account| parse_ticker_record(&self.program_id, &account.data))
+            .transpose()
+    }
+
+    /// Returns the token data of the mint, `None` when the mint is not registered
+    pub async fn resolve_mint(&self, mint: &Pubkey) -> Result<Option<TokenData>> {
+        Ok(self.get_token_data_batch(&[*mint]).await?.pop().flatten())
+    }
+
+    /// Fetches the records of the mints in chunks of `MAX_MULTIPLE_ACCOUNTS`, in the same order.
+    /// Both the current and the legacy record of every mint are requested
+    pub async fn get_token_data_batch(&self, mints: &[Pubkey]) -> Result<Vec<Option<TokenData>>> {
+        let mut token_data = Vec::with_capacity(mints.len());
+        for chunk in mints.chunks(MAX_MULTIPLE_ACCOUNTS / 2) {
+            let keys = chunk
+                .iter()
+                .flat_map(|mint| {
+                    [
+                        derive_mint_account(&self.program_id, mint),
+                        derive_legacy_mint_account(mint),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            let accounts = self.rpc_client.get_multiple_accounts(&keys).await?;
+            for pair in accounts.chunks(2) {
+                let account = pair[0].as_ref().or_else(|| pair[1].as_ref());
+                token_data.push(parse_token_account(&self.program_id, account)?);
+            }
+        }
+        Ok(token_data)
+    }
+
+    /// Lists the mint records of the registry including the legacy ones, ticker records are
+    /// skipped
+    pub async fn list_all(&self) -> Result<Vec<(Pubkey, TokenData)>> {
+        let accounts = self
+            .rpc_client
+            .get_program_accounts(
+                &spl_name_service::ID,
+                registry_record_filters(&self.program_id),
+            )
+            .await?;
+        Ok(token_records(&self.program_id, accounts))
+    }
+
+    /// Registers a token, the accounts are derived from the ticker and mint of `params`
+    /// and the treasury from `fee_mint`
+    pub async fn register(
+        &self,
+        fee_payer: &Keypair,
+        fee_mint: Pubkey,
+        fee_source: Pubkey,
+        metaplex_metadata_account: Option<Pubkey>,
+        params: instruction::register::Params,
+    ) -> Result<Signature> {
+        let mint =
+            Pubkey::from_str(&params.mint).map_err(|_| Error::InvalidMint(params.mint.clone()))?;
+        let instruction = register(
+            self.program_id,
+            fee_payer.pubkey(),
+            mint,
+            fee_mint,
+            fee_source,
+            metaplex_metadata_account,
+            params,
+        );
+        self.send(instruction, fee_payer).await
+    }
+
+    /// Updates the metadata of a token as an admin or as its update authority
+    pub async fn update(
+        &self,
+        authority: &Keypair,
+        params: instruction::update_metadata::Params,
+    ) -> Result<Signature> {
+        let mint =
+            Pubkey::from_str(&params.mint).map_err(|_| Error::InvalidMint(params.mint.clone()))?;
+        let instruction = update_metadata(
+            self.program_id,
+            authority.pubkey(),
+            derive_mint_account(&self.program_id, &mint),
+            Pubkey::from_str(TOKEN_TLD).unwrap(),
+            derive_central_state(&self.program_id).0,
+            params,
+        );
+        self.send(instruction, authority).await
+    }
+
+    async fn send(&self, instruction: Instruction, signer: &Keypair) -> Result<Signature> {
+        let blockhash = self.rpc_client.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&signer.pubkey()),
+            &[signer],
+            blockhash,
+        );
+        self.rpc_client
+            .send_and_confirm_transaction(&transaction)
+            .await
+    }
+}
+
+/// Name records whose parent is the Token TLD and whose owner is the central state, the legacy
+/// records have no class
+pub fn registry_record_filters(program_id: &Pubkey) -> Vec<RpcFilterType> {
+    let (central_state, _) = derive_central_state(program_id);
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            0,
+            &Pubkey::from_str(TOKEN_TLD).unwrap().to_bytes(),
+        )),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            OWNER_OFFSET,
+            &central_state.to_bytes(),
+        )),
+    ]
+}
+
+/// `data` must be the data of the account derived with `derive_ticker_account`
+pub fn parse_ticker_record(program_id: &Pubkey, data: &[u8]) -> Result<Pubkey> {
+    let record = TickerRecord::from_account_data(program_id, data)?;
+    Ok(Pubkey::new_from_array(record.data.mint))
+}
+
+/// A missing account is an unregistered mint rather than an error
+pub fn parse_token_account(
+    program_id: &Pubkey,
+    account: Option<&Account>,
+) -> Result<Option<TokenData>> {
+    account
+        .map(|account| Ok(TokenRecord::from_account_data(program_id, &account.data)?.data))
+        .transpose()
+}
+
+/// Keeps the accounts that parse as mint records and sit at the key derived from their mint
+pub fn token_records(
+    program_id: &Pubkey,
+    accounts: Vec<(Pubkey, Account)>,
+) -> Vec<(Pubkey, TokenData)> {
+    accounts
+        .into_iter()
+        .filter_map(|(key, account)| {
+            let record = TokenRecord::from_account_data(program_id, &account.data).ok()?;
+            let mint = Pubkey::new_from_array(record.data.mint);
+            let expected_key = if record.is_legacy() {
+                derive_legacy_mint_account(&mint)
+            } else {
+                derive_mint_account(program_id, &mint)
+            };
+            (key == expected_key).then(|| (key, record.data))
+        })
+        .collect()
+}
@@ -0,0 +1,374 @@
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use borsh::BorshSerialize;
+use solana_client::{client_error::ClientErrorKind, rpc_filter::RpcFilterType};
+use solana_program::{program_option::COption, program_pack::Pack, pubkey::Pubkey, system_program};
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    hash::Hash,
+    signature::{Signature, Signer},
+    transaction::Transaction,
+};
+use spl_name_service::state::NameRecordHeader;
+use token_registry::{
+    derivation::{derive_central_state, derive_mint_account, derive_ticker_account},
+    entrypoint::process_instruction,
+    instruction::{init, register},
+    state::{VerificationTier, TOKEN_TLD},
+};
+use token_registry_client::{
+    parse_ticker_record, parse_token_account, registry_record_filters, token_records, Error,
+    RegistryClient, RegistryRpc, Result, MAX_MULTIPLE_ACCOUNTS,
+};
+
+/// Serves the RPC calls of the client with the banks client. The banks client cannot scan the
+/// accounts of a program, they are looked up among `known_keys` instead
+struct BanksRpc {
+    banks_client: BanksClient,
+    known_keys: Vec<Pubkey>,
+    multiple_accounts_calls: AtomicUsize,
+}
+
+fn rpc_error(err: impl ToString) -> Error {
+    Error::Rpc(ClientErrorKind::Custom(err.to_string()).into())
+}
+
+#[async_trait]
+impl RegistryRpc for BanksRpc {
+    async fn get_multiple_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Account>>> {
+        assert!(keys.len() <= MAX_MULTIPLE_ACCOUNTS);
+        self.multiple_accounts_calls.fetch_add(1, Ordering::SeqCst);
+        let mut banks_client = self.banks_client.clone();
+        let mut accounts = Vec::with_capacity(keys.len());
+        for key in keys {
+            accounts.push(banks_client.get_account(*key).await.map_err(rpc_error)?);
+        }
+        Ok(accounts)
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+    ) -> Result<Vec<(Pubkey, Account)>> {
+        let accounts = self.get_multiple_accounts(&self.known_keys).await?;
+        Ok(self
+            .known_keys
+            .iter()
+            .zip(accounts)
+            .filter_map(|(key, account)| Some((*key, account?)))
+            .filter(|(_, account)| {
+                account.owner == *program_id
+                    && filters.iter().all(|filter| match filter {
+                        RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(&account.data),
+                        _ => unreachable!(),
+                    })
+            })
+            .collect())
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<Hash> {
+        let mut banks_client = self.banks_client.clone();
+        banks_client.get_latest_blockhash().await.map_err(rpc_error)
+    }
+
+    async fn send_and_confirm_transaction(&self, transaction: &Transaction) -> Result<Signature> {
+        let mut banks_client = self.banks_client.clone();
+        banks_client
+            .process_transaction(transaction.clone())
+            .await
+            .map_err(rpc_error)?;
+        Ok(transaction.signatures[0])
+    }
+}
+
+fn mint_account(decimals: u8) -> Account {
+    let mut mint_data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut mint_data);
+    Account {
+        lamports: 1_000_000_000,
+        data: mint_data,
+        owner: spl_token::ID,
+        ..Account::default()
+    }
+}
+
+/// Registry program test with the name service and the token TLD
+fn registry_program_test(program_id: Pubkey) -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "token_registry",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_program(
+        "spl_name_service",
+        spl_name_service::id(),
+        processor!(spl_name_service::processor::Processor::process_instruction),
+    );
+    let (central_state, _) = derive_central_state(&program_id);
+    program_test.add_account(
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        Account {
+            lamports: 1_000_000,
+            data: NameRecordHeader {
+                parent_name: Pubkey::default(),
+                owner: central_state,
+                class: Pubkey::default(),
+            }
+            .try_to_vec()
+            .unwrap(),
+            owner: spl_name_service::id(),
+            ..Account::default()
+        },
+    );
+    program_test
+}
+
+#[tokio::test]
+async fn test_parse_registry_accounts() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = registry_program_test(program_id);
+    let (central_state, nonce) = derive_central_state(&program_id);
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account(6));
+
+    let mut ctx = program_test.start_with_context().await;
+    let payer = ctx.payer.pubkey();
+    let instructions = [
+        init(
+            program_id,
+            central_state,
+            payer,
+            token_registry::instruction::init::Params {
+                signer_nonce: nonce,
+            },
+        ),
+        register(
+            program_id,
+            payer,
+            mint,
+            system_program::ID,
+            payer,
+            None,
+            token_registry::instruction::register::Params {
+                signer_index: Some(0),
+                name: "Test Token".to_string(),
+                ticker: "TEST".to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+                update_authority: None,
+            },
+        ),
+    ];
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let ticker_key = derive_ticker_account(&program_id, "TEST");
+    let mint_key = derive_mint_account(&program_id, &mint);
+    let ticker_account = ctx.banks_client.get_account(ticker_key).await.unwrap();
+    let mint_account = ctx.banks_client.get_account(mint_key).await.unwrap();
+
+    // Both records match the filters of `list_all`
+    for account in [&ticker_account, &mint_account] {
+        let data = &account.as_ref().unwrap().data;
+        for filter in registry_record_filters(&program_id) {
+            match filter {
+                RpcFilterType::Memcmp(memcmp) => assert!(memcmp.bytes_match(data)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    let resolved = parse_ticker_record(&program_id, &ticker_account.as_ref().unwrap().data);
+    assert_eq!(resolved.unwrap(), mint);
+
+    let token_data = parse_token_account(&program_id, mint_account.as_ref())
+        .unwrap()
+        .unwrap();
+    assert_eq!(token_data.ticker, "TEST");
+    assert_eq!(token_data.tier, VerificationTier::Verified);
+    assert!(parse_token_account(&program_id, None).unwrap().is_none());
+
+    // Ticker records, and mint records away from the key of their mint, are skipped when listing
+    // the registry
+    let mint_account = mint_account.unwrap();
+    let records = token_records(
+        &program_id,
+        vec![
+            (ticker_key, ticker_account.unwrap()),
+            (Pubkey::new_unique(), mint_account.clone()),
+            (mint_key, mint_account),
+        ],
+    );
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, mint_key);
+}
+
+fn register_params(ticker: &str, mint: &Pubkey) -> token_registry::instruction::register::Params {
+    token_registry::instruction::register::Params {
+        signer_index: Some(0),
+        name: format!("{} Token", ticker),
+        ticker: ticker.to_string(),
+        mint: mint.to_string(),
+        decimals: 6,
+        website: "".to_string(),
+        logo_uri: "".to_string(),
+        update_authority: None,
+    }
+}
+
+#[tokio::test]
+async fn test_client() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = registry_program_test(program_id);
+    let (central_state, nonce) = derive_central_state(&program_id);
+    let tickers = ["FIRST", "SECOND"];
+    let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+    for mint in mints.iter() {
+        program_test.add_account(*mint, mint_account(6));
+    }
+
+    let ctx = program_test.start_with_context().await;
+    let payer = &ctx.payer;
+    let known_keys = tickers
+        .iter()
+        .zip(mints.iter())
+        .flat_map(|(ticker, mint)| {
+            [
+                derive_ticker_account(&program_id, ticker),
+                derive_mint_account(&program_id, mint),
+            ]
+        })
+        .chain([Pubkey::from_str(TOKEN_TLD).unwrap()])
+        .collect();
+    let client = RegistryClient::new(
+        BanksRpc {
+            banks_client: ctx.banks_client.clone(),
+            known_keys,
+            multiple_accounts_calls: AtomicUsize::new(0),
+        },
+        program_id,
+    );
+
+    let blockhash = client.rpc_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[init(
+            program_id,
+            central_state,
+            payer.pubkey(),
+            token_registry::instruction::init::Params {
+                signer_nonce: nonce,
+            },
+        )],
+        Some(&payer.pubkey()),
+        &[payer],
+        blockhash,
+    );
+    client
+        .rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .await
+        .unwrap();
+
+    // Register
+    for (ticker, mint) in tickers.iter().zip(mints.iter()) {
+        client
+            .register(
+                payer,
+                system_program::ID,
+                payer.pubkey(),
+                None,
+                register_params(ticker, mint),
+            )
+            .await
+            .unwrap();
+    }
+    let mut params = register_params("THIRD", &mints[0]);
+    params.mint = "not a mint".to_string();
+    let result = client
+        .register(payer, system_program::ID, payer.pubkey(), None, params)
+        .await;
+    assert!(matches!(result, Err(Error::InvalidMint(_))));
+
+    // Resolve
+    assert_eq!(
+        client.resolve_ticker("FIRST").await.unwrap(),
+        Some(mints[0])
+    );
+    assert_eq!(
+        client.resolve_ticker("SECOND").await.unwrap(),
+        Some(mints[1])
+    );
+    assert_eq!(client.resolve_ticker("NONE").await.unwrap(), None);
+
+    // The batch is split in requests of at most `MAX_MULTIPLE_ACCOUNTS` keys, the mint and
+    // legacy record of each mint are requested together
+    let mut batch = (0..120).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    batch[0] = mints[0];
+    batch[119] = mints[1];
+    let calls = client
+        .rpc_client
+        .multiple_accounts_calls
+        .load(Ordering::SeqCst);
+    let token_data = client.get_token_data_batch(&batch).await.unwrap();
+    assert_eq!(
+        client
+            .rpc_client
+            .multiple_accounts_calls
+            .load(Ordering::SeqCst)
+            - calls,
+        3
+    );
+    assert_eq!(token_data.len(), batch.len());
+    assert_eq!(token_data[0].as_ref().unwrap().ticker, "FIRST");
+    assert_eq!(token_data[119].as_ref().unwrap().ticker, "SECOND");
+    assert!(token_data[1..119].iter().all(Option::is_none));
+
+    // Update
+    client
+        .update(
+            payer,
+            token_registry::instruction::update_metadata::Params {
+                signer_index: Some(0),
+                mint: mints[0].to_string(),
+                name: Some("Renamed".to_string()),
+                website: None,
+                logo_uri: None,
+            },
+        )
+        .await
+        .unwrap();
+    let token_data = client.resolve_mint(&mints[0]).await.unwrap().unwrap();
+    assert_eq!(token_data.name, "Renamed");
+    assert_eq!(token_data.ticker, "FIRST");
+
+    // List, ticker records and the TLD are skipped
+    let mut records = client.list_all().await.unwrap();
+    records.sort_by_key(|(_, token_data)| token_data.ticker.clone());
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].0, derive_mint_account(&program_id, &mints[0]));
+    assert_eq!(records[0].1.name, "Renamed");
+    assert_eq!(records[1].0, derive_mint_account(&program_id, &mints[1]));
+}
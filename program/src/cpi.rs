@@ -1,8 +1,14 @@
 use std::str::FromStr;
 
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
-    rent::Rent, system_instruction::create_account, sysvar::Sysvar,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::invoke_signed,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
 };
 use spl_name_service::instruction::NameRegistryInstruction;
 
@@ -48,6 +54,7 @@ impl Cpi {
         name_account: &AccountInfo<'a>,
         fee_payer: &AccountInfo<'a>,
         new_owner_account: &AccountInfo<'a>,
+        name_class: Option<&AccountInfo<'a>>,
         root_name_account: &AccountInfo<'a>,
         authority: &AccountInfo<'a>,
         hashed_name: Vec<u8>,
@@ -65,24 +72,25 @@ impl Cpi {
             *name_account.key,
             *fee_payer.key,
             *new_owner_account.key,
-            None,
+            name_class.map(|class| *class.key),
             Some(*root_name_account.key),
             Some(*authority.key),
         )?;
 
-        invoke_signed(
-            &create_name_instruction,
-            &[
-                name_service_program.clone(),
-                fee_payer.clone(),
-                name_account.clone(),
-                new_owner_account.clone(),
-                system_program_account.clone(),
-                root_name_account.clone(),
-                authority.clone(),
-            ],
-            &[signer_seeds],
-        )
+        let mut account_infos = vec![
+            name_service_program.clone(),
+            fee_payer.clone(),
+            name_account.clone(),
+            new_owner_account.clone(),
+            system_program_account.clone(),
+            root_name_account.clone(),
+            authority.clone(),
+        ];
+        if let Some(name_class) = name_class {
+            account_infos.push(name_class.clone());
+        }
+
+        invoke_signed(&create_name_instruction, &account_infos, &[signer_seeds])
     }
 
     pub fn update_name_account_data<'a>(
@@ -113,4 +121,116 @@ impl Cpi {
             &[signer_seeds],
         )
     }
+
+    pub fn delete_name_account<'a>(
+        name_service_program: &AccountInfo<'a>,
+        name_account: &AccountInfo<'a>,
+        name_owner: &AccountInfo<'a>,
+        refund_target: &AccountInfo<'a>,
+        signer_seeds: &[&[u8]],
+    ) -> ProgramResult {
+        let delete_name_instruction = spl_name_service::instruction::delete(
+            *name_service_program.key,
+            *name_account.key,
+            *name_owner.key,
+            *refund_target.key,
+        )?;
+
+        invoke_signed(
+            &delete_name_instruction,
+            &[
+                name_service_program.clone(),
+                name_account.clone(),
+                name_owner.clone(),
+                refund_target.clone(),
+            ],
+            &[signer_seeds],
+        )
+    }
+
+    pub fn transfer_lamports<'a>(
+        system_program: &AccountInfo<'a>,
+        source: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        amount: u64,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let transfer_instruction = transfer(source.key, destination.key, amount);
+
+        invoke_signed(
+            &transfer_instruction,
+            &[system_program.clone(), source.clone(), destination.clone()],
+            signers_seeds,
+        )
+    }
+
+    pub fn transfer_tokens<'a>(
+        spl_token_program: &AccountInfo<'a>,
+        source: &AccountInfo<'a>,
+        destination: &AccountInfo<'a>,
+        authority: &AccountInfo<'a>,
+        amount: u64,
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let transfer_instruction = spl_token::instruction::transfer(
+            spl_token_program.key,
+            source.key,
+            destination.key,
+            authority.key,
+            &[],
+            amount,
+        )?;
+
+        invoke_signed(
+            &transfer_instruction,
+            &[
+                spl_token_program.clone(),
+                source.clone(),
+                destination.clone(),
+                authority.clone(),
+            ],
+            signers_seeds,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_token_account<'a>(
+        spl_token_program: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        fee_payer: &AccountInfo<'a>,
+        token_account: &AccountInfo<'a>,
+        mint: &AccountInfo<'a>,
+        owner: &AccountInfo<'a>,
+        rent_sysvar_account: &AccountInfo<'a>,
+        signer_seeds: &[&[u8]],
+    ) -> ProgramResult {
+        Cpi::create_account(
+            spl_token_program.key,
+            system_program,
+            fee_payer,
+            token_account,
+            rent_sysvar_account,
+            signer_seeds,
+            spl_token::state::Account::LEN,
+        )?;
+
+        let initialize_instruction = spl_token::instruction::initialize_account(
+            spl_token_program.key,
+            token_account.key,
+            mint.key,
+            owner.key,
+        )?;
+
+        invoke_signed(
+            &initialize_instruction,
+            &[
+                spl_token_program.clone(),
+                token_account.clone(),
+                mint.clone(),
+                owner.clone(),
+                rent_sysvar_account.clone(),
+            ],
+            &[],
+        )
+    }
 }
@@ -0,0 +1,54 @@
+//! Keys of the registry accounts, name records are derived under the Token TLD with the
+//! central state as their class. Records issued before the class was introduced live at the
+//! class-less legacy addresses until they are migrated
+
+use std::str::FromStr;
+
+use solana_program::pubkey::Pubkey;
+use spl_name_service::state::get_seeds_and_key;
+
+use crate::{
+    state::{CentralState, TOKEN_TLD},
+    utils::get_hashed_name,
+};
+
+pub fn derive_central_state(program_id: &Pubkey) -> (Pubkey, u8) {
+    CentralState::find_key(program_id)
+}
+
+pub(crate) fn derive_name_account(program_id: &Pubkey, hashed_name: Vec<u8>) -> Pubkey {
+    get_seeds_and_key(
+        &spl_name_service::ID,
+        hashed_name,
+        Some(&derive_central_state(program_id).0),
+        Some(&Pubkey::from_str(TOKEN_TLD).unwrap()),
+    )
+    .0
+}
+
+pub(crate) fn derive_legacy_name_account(hashed_name: Vec<u8>) -> Pubkey {
+    get_seeds_and_key(
+        &spl_name_service::ID,
+        hashed_name,
+        None,
+        Some(&Pubkey::from_str(TOKEN_TLD).unwrap()),
+    )
+    .0
+}
+
+/// The ticker is hashed as given, it must be spelled the way it was registered
+pub fn derive_ticker_account(program_id: &Pubkey, ticker: &str) -> Pubkey {
+    derive_name_account(program_id, get_hashed_name(ticker))
+}
+
+pub fn derive_mint_account(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    derive_name_account(program_id, get_hashed_name(&mint.to_string()))
+}
+
+pub fn derive_legacy_ticker_account(ticker: &str) -> Pubkey {
+    derive_legacy_name_account(get_hashed_name(ticker))
+}
+
+pub fn derive_legacy_mint_account(mint: &Pubkey) -> Pubkey {
+    derive_legacy_name_account(get_hashed_name(&mint.to_string()))
+}
@@ -47,6 +47,87 @@ impl PrintProgramError for TokenRegistryError {
             TokenRegistryError::InvalidNameProvided => {
                 msg!("Error: Invalid name provided")
             }
+            TokenRegistryError::TickerReserved => {
+                msg!("Error: Ticker is reserved")
+            }
+            TokenRegistryError::MintDenylisted => {
+                msg!("Error: Mint is denylisted")
+            }
+            TokenRegistryError::SelfRegistrationDisabled => {
+                msg!("Error: Self registration is disabled")
+            }
+            TokenRegistryError::TickerNotReserved => {
+                msg!("Error: Ticker is not reserved")
+            }
+            TokenRegistryError::AuctionEnded => {
+                msg!("Error: Auction has ended")
+            }
+            TokenRegistryError::AuctionNotEnded => {
+                msg!("Error: Auction has not ended")
+            }
+            TokenRegistryError::BidTooLow => {
+                msg!("Error: Bid too low")
+            }
+            TokenRegistryError::TickerHasNoLease => {
+                msg!("Error: Ticker has no lease")
+            }
+            TokenRegistryError::LeaseNotExpired => {
+                msg!("Error: Lease has not expired")
+            }
+            TokenRegistryError::TickerInCooldown => {
+                msg!("Error: Ticker is in cooldown")
+            }
+            TokenRegistryError::BondedListingDisabled => {
+                msg!("Error: Bonded listings are disabled")
+            }
+            TokenRegistryError::ChallengeWindowClosed => {
+                msg!("Error: Challenge window has closed")
+            }
+            TokenRegistryError::ChallengeWindowOpen => {
+                msg!("Error: Challenge window is still open")
+            }
+            TokenRegistryError::AlreadyChallenged => {
+                msg!("Error: Listing is already challenged")
+            }
+            TokenRegistryError::NotChallenged => {
+                msg!("Error: Listing is not challenged")
+            }
+            TokenRegistryError::AttesterNotRegistered => {
+                msg!("Error: Attester is not registered")
+            }
+            TokenRegistryError::NotMintAuthority => {
+                msg!("Error: Signer is not the mint authority")
+            }
+            TokenRegistryError::MetadataTooLong => {
+                msg!("Error: Metadata does not fit in the record")
+            }
+            TokenRegistryError::TickerMismatch => {
+                msg!("Error: Ticker does not match the Metaplex symbol")
+            }
+            TokenRegistryError::InvalidRecordHeader => {
+                msg!("Error: Name record was not issued by the registry")
+            }
+            TokenRegistryError::InvalidRecordData => {
+                msg!("Error: Record data could not be parsed")
+            }
+            TokenRegistryError::AlreadyRegistered => {
+                msg!("Error: Name is already registered at its legacy address")
+            }
+            TokenRegistryError::TickerNotNormalized => {
+                msg!("Error: Ticker is not normalized")
+            }
+            TokenRegistryError::ListingPending => {
+                msg!("Error: Ticker has a pending bonded listing")
+            }
+            TokenRegistryError::AlreadyClaimed => {
+                msg!("Error: Entry is already claimed")
+            }
+            TokenRegistryError::LeaseExpired => {
+                msg!("Error: Ticker lease has expired")
+            }
+            TokenRegistryError::AuctionPending => {
+                msg!("Error: Ticker is being auctioned")
+            }
         }
     }
 }
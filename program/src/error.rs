@@ -14,6 +14,60 @@ pub enum TokenRegistryError {
     NonWhiteListedSigner = 3,
     #[error("Invalid name provided")]
     InvalidNameProvided = 4,
+    #[error("Ticker is reserved")]
+    TickerReserved = 5,
+    #[error("Mint is denylisted")]
+    MintDenylisted = 6,
+    #[error("Self registration is disabled")]
+    SelfRegistrationDisabled = 7,
+    #[error("Ticker is not reserved")]
+    TickerNotReserved = 8,
+    #[error("Auction has ended")]
+    AuctionEnded = 9,
+    #[error("Auction has not ended")]
+    AuctionNotEnded = 10,
+    #[error("Bid too low")]
+    BidTooLow = 11,
+    #[error("Ticker has no lease")]
+    TickerHasNoLease = 12,
+    #[error("Lease has not expired")]
+    LeaseNotExpired = 13,
+    #[error("Ticker is in cooldown")]
+    TickerInCooldown = 14,
+    #[error("Bonded listings are disabled")]
+    BondedListingDisabled = 15,
+    #[error("Challenge window has closed")]
+    ChallengeWindowClosed = 16,
+    #[error("Challenge window is still open")]
+    ChallengeWindowOpen = 17,
+    #[error("Listing is already challenged")]
+    AlreadyChallenged = 18,
+    #[error("Listing is not challenged")]
+    NotChallenged = 19,
+    #[error("Attester is not registered")]
+    AttesterNotRegistered = 20,
+    #[error("Signer is not the mint authority")]
+    NotMintAuthority = 21,
+    #[error("Metadata does not fit in the record")]
+    MetadataTooLong = 22,
+    #[error("Ticker does not match the Metaplex symbol")]
+    TickerMismatch = 23,
+    #[error("Name record was not issued by the registry")]
+    InvalidRecordHeader = 24,
+    #[error("Record data could not be parsed")]
+    InvalidRecordData = 25,
+    #[error("Name is already registered at its legacy address")]
+    AlreadyRegistered = 26,
+    #[error("Ticker is not normalized")]
+    TickerNotNormalized = 27,
+    #[error("Ticker has a pending bonded listing")]
+    ListingPending = 28,
+    #[error("Entry is already claimed")]
+    AlreadyClaimed = 29,
+    #[error("Ticker lease has expired")]
+    LeaseExpired = 30,
+    #[error("Ticker is being auctioned")]
+    AuctionPending = 31,
 }
 impl From<TokenRegistryError> for ProgramError {
     fn from(e: TokenRegistryError) -> Self {
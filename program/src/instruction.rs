@@ -1,5 +1,48 @@
+pub use crate::processor::add_attester;
+pub use crate::processor::allow_mint;
+pub use crate::processor::approve_application;
+pub use crate::processor::approve_claimant;
+pub use crate::processor::attest;
+pub use crate::processor::bid;
+pub use crate::processor::cancel_listing;
+pub use crate::processor::challenge;
+pub use crate::processor::claim_entry;
+pub use crate::processor::deny_mint;
+pub use crate::processor::emit;
+pub use crate::processor::finalize_listing;
 pub use crate::processor::init;
+pub use crate::processor::list_with_bond;
+pub use crate::processor::migrate_central_state;
+pub use crate::processor::migrate_entry;
+pub use crate::processor::reclaim;
+pub use crate::processor::refresh_extension_flags;
+pub use crate::processor::refund_auction;
 pub use crate::processor::register;
+pub use crate::processor::register_v1;
+pub use crate::processor::reject_application;
+pub use crate::processor::release;
+pub use crate::processor::remove_attester;
+pub use crate::processor::renew;
+pub use crate::processor::report;
+pub use crate::processor::reserve;
+pub use crate::processor::resolve_challenge;
+pub use crate::processor::resolve_mint;
+pub use crate::processor::resolve_report;
+pub use crate::processor::resolve_ticker;
+pub use crate::processor::revoke;
+pub use crate::processor::set_bond_terms;
+pub use crate::processor::set_fee_schedule;
+pub use crate::processor::set_lease_terms;
+pub use crate::processor::set_update_authority;
+pub use crate::processor::set_verification_tier;
+pub use crate::processor::settle_auction;
+pub use crate::processor::start_auction;
+pub use crate::processor::submit_application;
+pub use crate::processor::sync_from_metaplex;
+pub use crate::processor::update_metadata;
+pub use crate::processor::withdraw_fees;
+
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::sysvar;
@@ -11,6 +54,18 @@ use solana_program::{
 
 use spl_name_service;
 
+use crate::{
+    derivation::{
+        derive_central_state, derive_legacy_mint_account, derive_legacy_ticker_account,
+        derive_mint_account, derive_ticker_account,
+    },
+    state::{
+        Auction, BondedListing, CentralState, DeniedMint, ReservedTicker, Tombstone, TOKEN_TLD,
+    },
+    token_metadata::EMIT_DISCRIMINATOR,
+    utils::normalize_ticker,
+};
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub enum RegistryInstruction {
     // 0
@@ -27,6 +82,513 @@ pub enum RegistryInstruction {
     // 1
     // Accounts expected by this instructions
     //
+    // | Index | Writable | Signer | Description                        |
+    // |-------|----------|--------|------------------------------------|
+    // | 0     | ❌        | ❌      | Name service program               |
+    // | 1     | ❌        | ❌      | System program                     |
+    // | 2     | ✅        | ✅      | Fee payer (admin)                  |
+    // | 3     | ❌        | ❌      | Rent sysvar                        |
+    // | 4     | ✅        | ❌      | Legacy ticker name account         |
+    // | 5     | ✅        | ❌      | Legacy mint name account           |
+    // | 6     | ❌        | ❌      | Root name account (Token TLD)      |
+    // | 7     | ❌        | ❌      | Central state account              |
+    // | 8     | ❌        | ❌      | Ticker name account                |
+    // | 9     | ❌        | ❌      | Mint name account                  |
+    // | 10    | ❌        | ❌      | Reserved ticker account            |
+    // | 11    | ❌        | ❌      | Denied mint account                |
+    // | 12    | ❌        | ❌      | Bonded listing account             |
+    // | 13    | ❌        | ❌      | Auction account                    |
+    Register(register_v1::Params),
+
+    // 2
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ❌        | ❌      | System program          |
+    // | 1     | ✅        | ✅      | Fee payer (admin)       |
+    // | 2     | ❌        | ❌      | Rent sysvar             |
+    // | 3     | ✅        | ❌      | Reserved ticker account |
+    Reserve(reserve::Params),
+
+    // 3
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ✅        | ✅      | Fee payer (admin)       |
+    // | 1     | ✅        | ❌      | Reserved ticker account |
+    Release(release::Params),
+
+    // 4
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | System program                |
+    // | 1     | ✅        | ✅      | Fee payer (admin)             |
+    // | 2     | ❌        | ❌      | Rent sysvar                   |
+    // | 3     | ✅        | ❌      | Denied mint account           |
+    // | 4     | ❌        | ❌      | Name service program          |
+    // | 5     | ✅        | ❌      | Mint name account             |
+    // | 6     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 7     | ❌        | ❌      | Central state account         |
+    // | 8     | ❌        | ❌      | Legacy mint name account      |
+    DenyMint(deny_mint::Params),
+
+    // 5
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ✅        | ✅      | Fee payer (admin)             |
+    // | 1     | ✅        | ❌      | Denied mint account           |
+    // | 2     | ❌        | ❌      | Name service program          |
+    // | 3     | ✅        | ❌      | Mint name account             |
+    // | 4     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 5     | ❌        | ❌      | Central state account         |
+    AllowMint(allow_mint::Params),
+
+    // 6
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ❌      | System program        |
+    // | 1     | ❌        | ❌      | SPL token program     |
+    // | 2     | ✅        | ✅      | Fee payer (admin)     |
+    // | 3     | ❌        | ❌      | Rent sysvar           |
+    // | 4     | ✅        | ❌      | Central state account |
+    // | 5     | ✅        | ❌      | Treasury account      |
+    // | 6     | ❌        | ❌      | Fee mint              |
+    SetFeeSchedule(set_fee_schedule::Params),
+
+    // 7
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ❌      | System program        |
+    // | 1     | ❌        | ❌      | SPL token program     |
+    // | 2     | ❌        | ✅      | Admin                 |
+    // | 3     | ❌        | ❌      | Central state account |
+    // | 4     | ✅        | ❌      | Treasury account      |
+    // | 5     | ✅        | ❌      | Destination account   |
+    WithdrawFees(withdraw_fees::Params),
+
+    // 8
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ❌        | ❌      | System program          |
+    // | 1     | ✅        | ✅      | Fee payer (admin)       |
+    // | 2     | ❌        | ❌      | Rent sysvar             |
+    // | 3     | ✅        | ❌      | Auction account         |
+    // | 4     | ❌        | ❌      | Reserved ticker account |
+    StartAuction(start_auction::Params),
+
+    // 9
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ❌        | ❌      | System program          |
+    // | 1     | ✅        | ✅      | Bidder                  |
+    // | 2     | ✅        | ❌      | Auction account         |
+    // | 3     | ✅        | ❌      | Previous highest bidder |
+    Bid(bid::Params),
+
+    // 10
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ✅        | ✅      | Fee payer (winning bidder)    |
+    // | 3     | ✅        | ❌      | Ticker name account           |
+    // | 4     | ✅        | ❌      | Mint name account             |
+    // | 5     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 6     | ❌        | ❌      | Central state account         |
+    // | 7     | ✅        | ❌      | Auction account               |
+    // | 8     | ✅        | ❌      | Reserved ticker account       |
+    // | 9     | ❌        | ❌      | Denied mint account           |
+    // | 10    | ✅        | ❌      | Treasury account (SOL)        |
+    // | 11    | ❌        | ❌      | Mint account                  |
+    // | 12    | ❌        | ❌      | Legacy ticker name account    |
+    // | 13    | ❌        | ❌      | Legacy mint name account      |
+    // | 14    | ❌        | ❌      | Bonded listing account        |
+    SettleAuction(settle_auction::Params),
+
+    // 11
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ✅      | Admin                 |
+    // | 1     | ✅        | ❌      | Central state account |
+    SetLeaseTerms(set_lease_terms::Params),
+
+    // 12
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ❌        | ❌      | SPL token program             |
+    // | 3     | ✅        | ✅      | Fee payer                     |
+    // | 4     | ✅        | ❌      | Fee source token account      |
+    // | 5     | ✅        | ❌      | Ticker name account           |
+    // | 6     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 7     | ❌        | ❌      | Central state account         |
+    // | 8     | ✅        | ❌      | Treasury account              |
+    Renew(renew::Params),
+
+    // 13
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ❌        | ❌      | Name service program   |
+    // | 1     | ❌        | ❌      | System program         |
+    // | 2     | ✅        | ✅      | Fee payer              |
+    // | 3     | ❌        | ❌      | Rent sysvar            |
+    // | 4     | ✅        | ❌      | Ticker name account    |
+    // | 5     | ❌        | ❌      | Central state account  |
+    // | 6     | ✅        | ❌      | Tombstone account      |
+    // | 7     | ✅        | ❌      | Treasury account (SOL) |
+    Reclaim(reclaim::Params),
+
+    // 14
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ❌        | ❌      | System program          |
+    // | 1     | ✅        | ✅      | Applicant               |
+    // | 2     | ❌        | ❌      | Rent sysvar             |
+    // | 3     | ✅        | ❌      | Application account     |
+    // | 4     | ❌        | ❌      | Central state account   |
+    // | 5     | ❌        | ❌      | Reserved ticker account |
+    // | 6     | ❌        | ❌      | Denied mint account     |
+    // | 7     | ✅        | ❌      | Treasury account        |
+    // | 8     | ✅        | ❌      | Fee source account      |
+    // | 9     | ❌        | ❌      | SPL token program       |
+    SubmitApplication(submit_application::Params),
+
+    // 15
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ✅        | ✅      | Admin                         |
+    // | 3     | ✅        | ❌      | Ticker name account           |
+    // | 4     | ✅        | ❌      | Mint name account             |
+    // | 5     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 6     | ❌        | ❌      | Central state account         |
+    // | 7     | ✅        | ❌      | Application account           |
+    // | 8     | ❌        | ❌      | Reserved ticker account       |
+    // | 9     | ❌        | ❌      | Denied mint account           |
+    // | 10    | ✅        | ❌      | Applicant                     |
+    // | 11    | ❌        | ❌      | Mint account                  |
+    // | 12    | ❌        | ❌      | Legacy ticker name account    |
+    // | 13    | ❌        | ❌      | Legacy mint name account      |
+    // | 14    | ❌        | ❌      | Tombstone account             |
+    // | 15    | ❌        | ❌      | Bonded listing account        |
+    ApproveApplication(approve_application::Params),
+
+    // 16
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ✅      | Admin                 |
+    // | 1     | ❌        | ❌      | Central state account |
+    // | 2     | ✅        | ❌      | Application account   |
+    // | 3     | ✅        | ❌      | SOL treasury account  |
+    // | 4     | ✅        | ❌      | Applicant             |
+    RejectApplication(reject_application::Params),
+
+    // 17
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ✅      | Admin                 |
+    // | 1     | ✅        | ❌      | Central state account |
+    SetBondTerms(set_bond_terms::Params),
+
+    // 18
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description             |
+    // |-------|----------|--------|-------------------------|
+    // | 0     | ❌        | ❌      | System program          |
+    // | 1     | ✅        | ✅      | Registrant              |
+    // | 2     | ❌        | ❌      | Rent sysvar             |
+    // | 3     | ✅        | ❌      | Bonded listing account  |
+    // | 4     | ❌        | ❌      | Central state account   |
+    // | 5     | ❌        | ❌      | Reserved ticker account |
+    // | 6     | ❌        | ❌      | Denied mint account     |
+    // | 7     | ✅        | ❌      | Treasury account        |
+    // | 8     | ✅        | ❌      | Fee source account      |
+    // | 9     | ❌        | ❌      | SPL token program       |
+    ListWithBond(list_with_bond::Params),
+
+    // 19
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ❌        | ❌      | System program         |
+    // | 1     | ✅        | ✅      | Challenger             |
+    // | 2     | ✅        | ❌      | Bonded listing account |
+    Challenge(challenge::Params),
+
+    // 20
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ❌        | ✅      | Admin or arbiter       |
+    // | 1     | ❌        | ❌      | Central state account  |
+    // | 2     | ✅        | ❌      | Bonded listing account |
+    // | 3     | ✅        | ❌      | Registrant             |
+    // | 4     | ✅        | ❌      | Challenger             |
+    ResolveChallenge(resolve_challenge::Params),
+
+    // 21
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ✅        | ✅      | Fee payer                     |
+    // | 3     | ✅        | ❌      | Ticker name account           |
+    // | 4     | ✅        | ❌      | Mint name account             |
+    // | 5     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 6     | ❌        | ❌      | Central state account         |
+    // | 7     | ✅        | ❌      | Bonded listing account        |
+    // | 8     | ❌        | ❌      | Reserved ticker account       |
+    // | 9     | ❌        | ❌      | Denied mint account           |
+    // | 10    | ❌        | ❌      | Tombstone account             |
+    // | 11    | ✅        | ❌      | Registrant                    |
+    // | 12    | ❌        | ❌      | Mint account                  |
+    // | 13    | ❌        | ❌      | Legacy ticker name account    |
+    // | 14    | ❌        | ❌      | Legacy mint name account      |
+    FinalizeListing(finalize_listing::Params),
+
+    // 22
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ✅        | ✅      | Reporter                      |
+    // | 3     | ❌        | ❌      | Rent sysvar                   |
+    // | 4     | ✅        | ❌      | Mint name account             |
+    // | 5     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 6     | ❌        | ❌      | Central state account         |
+    // | 7     | ✅        | ❌      | Report account                |
+    Report(report::Params),
+
+    // 23
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ✅      | Admin                         |
+    // | 2     | ✅        | ❌      | Mint name account             |
+    // | 3     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 4     | ❌        | ❌      | Central state account         |
+    // | 5     | ✅        | ❌      | Report account                |
+    // | 6     | ✅        | ❌      | Reporter                      |
+    // | 7     | ✅        | ❌      | SOL treasury account          |
+    ResolveReport(resolve_report::Params),
+
+    // 24
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description      |
+    // |-------|----------|--------|------------------|
+    // | 0     | ❌        | ❌      | System program   |
+    // | 1     | ✅        | ✅      | Fee payer        |
+    // | 2     | ❌        | ❌      | Rent sysvar      |
+    // | 3     | ✅        | ❌      | Attester account |
+    AddAttester(add_attester::Params),
+
+    // 25
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description      |
+    // |-------|----------|--------|------------------|
+    // | 0     | ✅        | ✅      | Fee payer        |
+    // | 1     | ✅        | ❌      | Attester account |
+    RemoveAttester(remove_attester::Params),
+
+    // 26
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description         |
+    // |-------|----------|--------|---------------------|
+    // | 0     | ❌        | ❌      | System program      |
+    // | 1     | ✅        | ✅      | Attester            |
+    // | 2     | ❌        | ❌      | Rent sysvar         |
+    // | 3     | ❌        | ❌      | Attester account    |
+    // | 4     | ❌        | ❌      | Mint name account   |
+    // | 5     | ✅        | ❌      | Attestation account |
+    Attest(attest::Params),
+
+    // 27
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description         |
+    // |-------|----------|--------|---------------------|
+    // | 0     | ✅        | ✅      | Attester            |
+    // | 1     | ✅        | ❌      | Attestation account |
+    Revoke(revoke::Params),
+
+    // 28
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ✅      | Admin                         |
+    // | 2     | ✅        | ❌      | Mint name account             |
+    // | 3     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 4     | ❌        | ❌      | Central state account         |
+    SetVerificationTier(set_verification_tier::Params),
+
+    // 29
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ✅      | Admin                         |
+    // | 2     | ✅        | ❌      | Mint name account             |
+    // | 3     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 4     | ❌        | ❌      | Central state account         |
+    SetUpdateAuthority(set_update_authority::Params),
+
+    // 30
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ✅      | Admin or update authority     |
+    // | 2     | ✅        | ❌      | Mint name account             |
+    // | 3     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 4     | ❌        | ❌      | Central state account         |
+    UpdateMetadata(update_metadata::Params),
+
+    // 31
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ❌        | ❌      | System program         |
+    // | 1     | ✅        | ✅      | Fee payer              |
+    // | 2     | ❌        | ❌      | Rent sysvar            |
+    // | 3     | ✅        | ❌      | Claim approval account |
+    ApproveClaimant(approve_claimant::Params),
+
+    // 32
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ✅        | ✅      | Claimant                      |
+    // | 2     | ✅        | ❌      | Mint name account             |
+    // | 3     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 4     | ❌        | ❌      | Central state account         |
+    // | 5     | ❌        | ❌      | Mint account                  |
+    // | 6     | ✅        | ❌      | Claim approval account        |
+    // | 7     | ❌        | ❌      | Bonded listing account        |
+    ClaimEntry(claim_entry::Params),
+
+    // 33
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ✅        | ❌      | Mint name account             |
+    // | 2     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 3     | ❌        | ❌      | Central state account         |
+    // | 4     | ❌        | ❌      | Mint account                  |
+    RefreshExtensionFlags(refresh_extension_flags::Params),
+
+    // 34
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ✅        | ❌      | Mint name account             |
+    // | 2     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 3     | ❌        | ❌      | Central state account         |
+    // | 4     | ❌        | ❌      | Metaplex metadata account     |
+    SyncFromMetaplex(sync_from_metaplex::Params),
+
+    // 35
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description         |
+    // |-------|----------|--------|---------------------|
+    // | 0     | ❌        | ❌      | Ticker name account |
+    ResolveTicker(resolve_ticker::Params),
+
+    // 36
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description       |
+    // |-------|----------|--------|-------------------|
+    // | 0     | ❌        | ❌      | Mint name account |
+    ResolveMint(resolve_mint::Params),
+
+    // 37
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description           |
+    // |-------|----------|--------|-----------------------|
+    // | 0     | ❌        | ❌      | System program        |
+    // | 1     | ✅        | ✅      | Fee payer             |
+    // | 2     | ❌        | ❌      | Rent sysvar           |
+    // | 3     | ✅        | ❌      | Central state account |
+    MigrateCentralState(migrate_central_state::Params),
+
+    // 38
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description                   |
+    // |-------|----------|--------|-------------------------------|
+    // | 0     | ❌        | ❌      | Name service program          |
+    // | 1     | ❌        | ❌      | System program                |
+    // | 2     | ✅        | ✅      | Fee payer                     |
+    // | 3     | ✅        | ❌      | Legacy ticker name account    |
+    // | 4     | ✅        | ❌      | Legacy mint name account      |
+    // | 5     | ✅        | ❌      | Ticker name account           |
+    // | 6     | ✅        | ❌      | Mint name account             |
+    // | 7     | ❌        | ❌      | Root name account (Token TLD) |
+    // | 8     | ❌        | ❌      | Central state account         |
+    // | 9     | ❌        | ❌      | Mint account                  |
+    // | 10    | ❌        | ❌      | Denied mint account           |
+    MigrateEntry(migrate_entry::Params),
+
+    // 39
+    // Accounts expected by this instructions
+    //
     // | Index | Writable | Signer | Description                   |
     // |-------|----------|--------|-------------------------------|
     // | 0     | ❌        | ❌      | Name service program          |
@@ -37,7 +599,38 @@ pub enum RegistryInstruction {
     // | 5     | ✅        | ❌      | Mint name account             |
     // | 6     | ❌        | ❌      | Root name account (Token TLD) |
     // | 7     | ❌        | ❌      | Central state account         |
-    Register(register::Params),
+    // | 8     | ❌        | ❌      | Reserved ticker account       |
+    // | 9     | ❌        | ❌      | Denied mint account           |
+    // | 10    | ✅        | ❌      | Treasury account              |
+    // | 11    | ✅        | ❌      | Fee source token account      |
+    // | 12    | ❌        | ❌      | SPL token program             |
+    // | 13    | ❌        | ❌      | Tombstone account             |
+    // | 14    | ❌        | ❌      | Mint account                  |
+    // | 15    | ❌        | ❌      | Legacy ticker name account    |
+    // | 16    | ❌        | ❌      | Legacy mint name account      |
+    // | 17    | ❌        | ❌      | Bonded listing account        |
+    // | 18    | ❌        | ❌      | Auction account               |
+    // | 19    | ❌        | ❌      | Metaplex metadata (optional)  |
+    RegisterV2(register::Params),
+
+    // 40
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ✅        | ❌      | Auction account        |
+    // | 1     | ✅        | ❌      | Highest bidder         |
+    // | 2     | ✅        | ❌      | Treasury account (SOL) |
+    RefundAuction(refund_auction::Params),
+
+    // 41
+    // Accounts expected by this instructions
+    //
+    // | Index | Writable | Signer | Description            |
+    // |-------|----------|--------|------------------------|
+    // | 0     | ✅        | ✅      | Registrant             |
+    // | 1     | ✅        | ❌      | Bonded listing account |
+    CancelListing(cancel_listing::Params),
 }
 
 pub fn init(
@@ -62,26 +655,1171 @@ pub fn init(
     }
 }
 
-pub fn register(
+/// Builds the legacy `Register` instruction, `params.mint` is set from `mint`. The current
+/// name accounts are passed so that the registry checks that they are not registered
+pub fn register_v1(
     token_registry_program_id: Pubkey,
     fee_payer: Pubkey,
-    ticker_name_account: Pubkey,
-    mint_name_account: Pubkey,
-    root_name_account: Pubkey,
-    central_state: Pubkey,
-    params: register::Params,
+    mint: Pubkey,
+    mut params: register_v1::Params,
 ) -> Instruction {
+    params.mint = mint.to_string();
+    let legacy_ticker_name_account = derive_legacy_ticker_account(&params.ticker);
+    let ticker_name_account = derive_ticker_account(&token_registry_program_id, &params.ticker);
+    let (central_state, _) = derive_central_state(&token_registry_program_id);
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (reserved_ticker_account, _) =
+        ReservedTicker::find_key(&token_registry_program_id, &normalized_ticker);
+    let (denied_mint_account, _) = DeniedMint::find_key(&token_registry_program_id, &mint);
+    let (listing_account, _) =
+        BondedListing::find_key(&token_registry_program_id, &normalized_ticker);
+    let (auction_account, _) = Auction::find_key(&token_registry_program_id, &normalized_ticker);
     let instruction_data = RegistryInstruction::Register(params);
     let data = instruction_data.try_to_vec().unwrap();
     let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(legacy_ticker_name_account, false),
+        AccountMeta::new(derive_legacy_mint_account(&mint), false),
+        AccountMeta::new(Pubkey::from_str(TOKEN_TLD).unwrap(), false),
+        AccountMeta::new(central_state, false),
+        AccountMeta::new_readonly(ticker_name_account, false),
+        AccountMeta::new_readonly(
+            derive_mint_account(&token_registry_program_id, &mint),
+            false,
+        ),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new_readonly(listing_account, false),
+        AccountMeta::new_readonly(auction_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+/// The name records and PDAs are derived from the ticker of `params` and from `mint`, which
+/// `params.mint` is set from. The treasury is derived from `fee_mint`, the fee mint of the
+/// central state
+pub fn register(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    mint: Pubkey,
+    fee_mint: Pubkey,
+    fee_source: Pubkey,
+    metaplex_metadata_account: Option<Pubkey>,
+    mut params: register::Params,
+) -> Instruction {
+    params.mint = mint.to_string();
+    let ticker_name_account = derive_ticker_account(&token_registry_program_id, &params.ticker);
+    let mint_name_account = derive_mint_account(&token_registry_program_id, &mint);
+    let (central_state, _) = derive_central_state(&token_registry_program_id);
+    // Reservations and tombstones are keyed by the normalized ticker
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (reserved_ticker_account, _) =
+        ReservedTicker::find_key(&token_registry_program_id, &normalized_ticker);
+    let (denied_mint_account, _) = DeniedMint::find_key(&token_registry_program_id, &mint);
+    let (tombstone_account, _) =
+        Tombstone::find_key(&token_registry_program_id, &normalized_ticker);
+    let (listing_account, _) =
+        BondedListing::find_key(&token_registry_program_id, &normalized_ticker);
+    let (auction_account, _) = Auction::find_key(&token_registry_program_id, &normalized_ticker);
+    let (treasury, _) = CentralState::find_treasury_key(&token_registry_program_id, &fee_mint);
+
+    let ticker = params.ticker.clone();
+    let instruction_data = RegistryInstruction::RegisterV2(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let mut accounts = vec![
         AccountMeta::new_readonly(spl_name_service::ID, false),
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new(fee_payer, true),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
         AccountMeta::new(ticker_name_account, false),
         AccountMeta::new(mint_name_account, false),
-        AccountMeta::new(root_name_account, false),
+        AccountMeta::new(Pubkey::from_str(TOKEN_TLD).unwrap(), false),
         AccountMeta::new(central_state, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(fee_source, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(tombstone_account, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(derive_legacy_ticker_account(&ticker), false),
+        AccountMeta::new_readonly(derive_legacy_mint_account(&mint), false),
+        AccountMeta::new_readonly(listing_account, false),
+        AccountMeta::new_readonly(auction_account, false),
+    ];
+    if let Some(metaplex_metadata_account) = metaplex_metadata_account {
+        accounts.push(AccountMeta::new_readonly(metaplex_metadata_account, false));
+    }
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn reserve(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    reserved_ticker_account: Pubkey,
+    params: reserve::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Reserve(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(reserved_ticker_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn release(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    reserved_ticker_account: Pubkey,
+    params: release::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Release(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(reserved_ticker_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deny_mint(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    denied_mint_account: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    legacy_mint_name_account: Pubkey,
+    params: deny_mint::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::DenyMint(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(denied_mint_account, false),
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(legacy_mint_name_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn allow_mint(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    denied_mint_account: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    params: allow_mint::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::AllowMint(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(denied_mint_account, false),
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn set_fee_schedule(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    central_state: Pubkey,
+    treasury: Pubkey,
+    params: set_fee_schedule::Params,
+) -> Instruction {
+    let fee_mint = params.fee_mint;
+    let instruction_data = RegistryInstruction::SetFeeSchedule(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(central_state, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new_readonly(fee_mint, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn withdraw_fees(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    central_state: Pubkey,
+    treasury: Pubkey,
+    destination: Pubkey,
+    params: withdraw_fees::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::WithdrawFees(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(destination, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn start_auction(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    auction_account: Pubkey,
+    reserved_ticker_account: Pubkey,
+    params: start_auction::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::StartAuction(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(auction_account, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn bid(
+    token_registry_program_id: Pubkey,
+    bidder: Pubkey,
+    auction_account: Pubkey,
+    previous_bidder: Pubkey,
+    params: bid::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Bid(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(bidder, true),
+        AccountMeta::new(auction_account, false),
+        AccountMeta::new(previous_bidder, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn settle_auction(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    ticker_name_account: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    auction_account: Pubkey,
+    reserved_ticker_account: Pubkey,
+    denied_mint_account: Pubkey,
+    treasury: Pubkey,
+    mint_account: Pubkey,
+    legacy_ticker_name_account: Pubkey,
+    legacy_mint_name_account: Pubkey,
+    listing_account: Pubkey,
+    params: settle_auction::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SettleAuction(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(auction_account, false),
+        AccountMeta::new(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new_readonly(mint_account, false),
+        AccountMeta::new_readonly(legacy_ticker_name_account, false),
+        AccountMeta::new_readonly(legacy_mint_name_account, false),
+        AccountMeta::new_readonly(listing_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn set_lease_terms(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    central_state: Pubkey,
+    params: set_lease_terms::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SetLeaseTerms(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn renew(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    fee_source: Pubkey,
+    ticker_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    treasury: Pubkey,
+    params: renew::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Renew(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(fee_source, false),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(treasury, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn reclaim(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    ticker_name_account: Pubkey,
+    central_state: Pubkey,
+    tombstone_account: Pubkey,
+    params: reclaim::Params,
+) -> Instruction {
+    let (treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+    let instruction_data = RegistryInstruction::Reclaim(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(tombstone_account, false),
+        AccountMeta::new(treasury, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn submit_application(
+    token_registry_program_id: Pubkey,
+    applicant: Pubkey,
+    application_account: Pubkey,
+    central_state: Pubkey,
+    reserved_ticker_account: Pubkey,
+    denied_mint_account: Pubkey,
+    treasury: Pubkey,
+    fee_source: Pubkey,
+    params: submit_application::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SubmitApplication(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(applicant, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(application_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(fee_source, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn approve_application(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    ticker_name_account: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    application_account: Pubkey,
+    reserved_ticker_account: Pubkey,
+    denied_mint_account: Pubkey,
+    applicant: Pubkey,
+    mint_account: Pubkey,
+    legacy_ticker_name_account: Pubkey,
+    legacy_mint_name_account: Pubkey,
+    tombstone_account: Pubkey,
+    listing_account: Pubkey,
+    params: approve_application::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ApproveApplication(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(admin, true),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(application_account, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new(applicant, false),
+        AccountMeta::new_readonly(mint_account, false),
+        AccountMeta::new_readonly(legacy_ticker_name_account, false),
+        AccountMeta::new_readonly(legacy_mint_name_account, false),
+        AccountMeta::new_readonly(tombstone_account, false),
+        AccountMeta::new_readonly(listing_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn reject_application(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    central_state: Pubkey,
+    application_account: Pubkey,
+    treasury: Pubkey,
+    applicant: Pubkey,
+    params: reject_application::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::RejectApplication(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(application_account, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(applicant, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn set_bond_terms(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    central_state: Pubkey,
+    params: set_bond_terms::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SetBondTerms(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn list_with_bond(
+    token_registry_program_id: Pubkey,
+    registrant: Pubkey,
+    listing_account: Pubkey,
+    central_state: Pubkey,
+    reserved_ticker_account: Pubkey,
+    denied_mint_account: Pubkey,
+    treasury: Pubkey,
+    fee_source: Pubkey,
+    params: list_with_bond::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ListWithBond(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(registrant, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(listing_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new(treasury, false),
+        AccountMeta::new(fee_source, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn challenge(
+    token_registry_program_id: Pubkey,
+    challenger: Pubkey,
+    listing_account: Pubkey,
+    params: challenge::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Challenge(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(challenger, true),
+        AccountMeta::new(listing_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn resolve_challenge(
+    token_registry_program_id: Pubkey,
+    resolver: Pubkey,
+    central_state: Pubkey,
+    listing_account: Pubkey,
+    registrant: Pubkey,
+    challenger: Pubkey,
+    params: resolve_challenge::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ResolveChallenge(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(resolver, true),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(listing_account, false),
+        AccountMeta::new(registrant, false),
+        AccountMeta::new(challenger, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn finalize_listing(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    ticker_name_account: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    listing_account: Pubkey,
+    reserved_ticker_account: Pubkey,
+    denied_mint_account: Pubkey,
+    tombstone_account: Pubkey,
+    registrant: Pubkey,
+    mint_account: Pubkey,
+    legacy_ticker_name_account: Pubkey,
+    legacy_mint_name_account: Pubkey,
+    params: finalize_listing::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::FinalizeListing(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(listing_account, false),
+        AccountMeta::new_readonly(reserved_ticker_account, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+        AccountMeta::new_readonly(tombstone_account, false),
+        AccountMeta::new(registrant, false),
+        AccountMeta::new_readonly(mint_account, false),
+        AccountMeta::new_readonly(legacy_ticker_name_account, false),
+        AccountMeta::new_readonly(legacy_mint_name_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn report(
+    token_registry_program_id: Pubkey,
+    reporter: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    report_account: Pubkey,
+    params: report::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Report(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(reporter, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(report_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_report(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    report_account: Pubkey,
+    reporter: Pubkey,
+    treasury: Pubkey,
+    params: resolve_report::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ResolveReport(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new(report_account, false),
+        AccountMeta::new(reporter, false),
+        AccountMeta::new(treasury, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn add_attester(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    attester_account: Pubkey,
+    params: add_attester::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::AddAttester(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(attester_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn remove_attester(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    attester_account: Pubkey,
+    params: remove_attester::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::RemoveAttester(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(attester_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn attest(
+    token_registry_program_id: Pubkey,
+    attester: Pubkey,
+    attester_account: Pubkey,
+    mint_name_account: Pubkey,
+    attestation_account: Pubkey,
+    params: attest::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Attest(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(attester, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new_readonly(attester_account, false),
+        AccountMeta::new_readonly(mint_name_account, false),
+        AccountMeta::new(attestation_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn revoke(
+    token_registry_program_id: Pubkey,
+    attester: Pubkey,
+    attestation_account: Pubkey,
+    params: revoke::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::Revoke(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(attester, true),
+        AccountMeta::new(attestation_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn set_verification_tier(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    params: set_verification_tier::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SetVerificationTier(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn set_update_authority(
+    token_registry_program_id: Pubkey,
+    admin: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    params: set_update_authority::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SetUpdateAuthority(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(admin, true),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn update_metadata(
+    token_registry_program_id: Pubkey,
+    authority: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    params: update_metadata::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::UpdateMetadata(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(authority, true),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn approve_claimant(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    claim_approval_account: Pubkey,
+    params: approve_claimant::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ApproveClaimant(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(claim_approval_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn claim_entry(
+    token_registry_program_id: Pubkey,
+    claimant: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    mint_account: Pubkey,
+    claim_approval_account: Pubkey,
+    listing_account: Pubkey,
+    params: claim_entry::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ClaimEntry(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new(claimant, true),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(mint_account, false),
+        AccountMeta::new(claim_approval_account, false),
+        AccountMeta::new_readonly(listing_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn refresh_extension_flags(
+    token_registry_program_id: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    mint_account: Pubkey,
+    params: refresh_extension_flags::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::RefreshExtensionFlags(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(mint_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn sync_from_metaplex(
+    token_registry_program_id: Pubkey,
+    mint_name_account: Pubkey,
+    root_name_account: Pubkey,
+    central_state: Pubkey,
+    metaplex_metadata_account: Pubkey,
+    params: sync_from_metaplex::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::SyncFromMetaplex(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new(mint_name_account, false),
+        AccountMeta::new_readonly(root_name_account, false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(metaplex_metadata_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Uses the token-metadata interface encoding rather than `RegistryInstruction`
+pub fn emit(
+    token_registry_program_id: Pubkey,
+    mint_name_account: Pubkey,
+    params: emit::Params,
+) -> Instruction {
+    let mut data = EMIT_DISCRIMINATOR.to_vec();
+    data.extend(params.try_to_vec().unwrap());
+    let accounts = vec![AccountMeta::new_readonly(mint_name_account, false)];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn resolve_ticker(
+    token_registry_program_id: Pubkey,
+    ticker_name_account: Pubkey,
+    params: resolve_ticker::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ResolveTicker(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![AccountMeta::new_readonly(ticker_name_account, false)];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn resolve_mint(
+    token_registry_program_id: Pubkey,
+    mint_name_account: Pubkey,
+    params: resolve_mint::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::ResolveMint(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![AccountMeta::new_readonly(mint_name_account, false)];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn migrate_central_state(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    central_state: Pubkey,
+    params: migrate_central_state::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::MigrateCentralState(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(central_state, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+/// `mint` is the mint of the legacy ticker record
+pub fn migrate_entry(
+    token_registry_program_id: Pubkey,
+    fee_payer: Pubkey,
+    mint: Pubkey,
+    params: migrate_entry::Params,
+) -> Instruction {
+    let legacy_ticker_name_account = derive_legacy_ticker_account(&params.ticker);
+    let ticker_name_account = derive_ticker_account(
+        &token_registry_program_id,
+        &normalize_ticker(&params.ticker),
+    );
+    let (central_state, _) = derive_central_state(&token_registry_program_id);
+    let (denied_mint_account, _) = DeniedMint::find_key(&token_registry_program_id, &mint);
+    let instruction_data = RegistryInstruction::MigrateEntry(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new_readonly(spl_name_service::ID, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(fee_payer, true),
+        AccountMeta::new(legacy_ticker_name_account, false),
+        AccountMeta::new(derive_legacy_mint_account(&mint), false),
+        AccountMeta::new(ticker_name_account, false),
+        AccountMeta::new(
+            derive_mint_account(&token_registry_program_id, &mint),
+            false,
+        ),
+        AccountMeta::new_readonly(Pubkey::from_str(TOKEN_TLD).unwrap(), false),
+        AccountMeta::new_readonly(central_state, false),
+        AccountMeta::new_readonly(mint, false),
+        AccountMeta::new_readonly(denied_mint_account, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn refund_auction(
+    token_registry_program_id: Pubkey,
+    auction_account: Pubkey,
+    highest_bidder: Pubkey,
+    params: refund_auction::Params,
+) -> Instruction {
+    let (treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+    let instruction_data = RegistryInstruction::RefundAuction(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(auction_account, false),
+        AccountMeta::new(highest_bidder, false),
+        AccountMeta::new(treasury, false),
+    ];
+
+    Instruction {
+        program_id: token_registry_program_id,
+        accounts,
+        data,
+    }
+}
+
+pub fn cancel_listing(
+    token_registry_program_id: Pubkey,
+    registrant: Pubkey,
+    listing_account: Pubkey,
+    params: cancel_listing::Params,
+) -> Instruction {
+    let instruction_data = RegistryInstruction::CancelListing(params);
+    let data = instruction_data.try_to_vec().unwrap();
+    let accounts = vec![
+        AccountMeta::new(registrant, true),
+        AccountMeta::new(listing_account, false),
     ];
 
     Instruction {
@@ -1,10 +1,20 @@
+pub mod derivation;
 pub mod entrypoint;
 pub mod error;
 
 pub mod instruction;
 
+pub mod metaplex;
+
 pub mod state;
 
+pub mod token_2022;
+
+pub mod token_metadata;
+
+#[cfg(feature = "no-entrypoint")]
+pub mod verify;
+
 pub(crate) mod processor;
 
 pub(crate) mod utils;
@@ -0,0 +1,41 @@
+//! Minimal Metaplex token metadata definitions, only the leading fields of the metadata account are read
+
+use borsh::BorshDeserialize;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+solana_program::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+pub const SEED: &[u8] = b"metadata";
+/// `Key::MetadataV1`
+pub const METADATA_KEY: u8 = 4;
+
+/// Leading fields of a Metaplex metadata account, strings are stripped of their zero padding
+#[derive(BorshDeserialize, Debug)]
+pub struct Metadata {
+    pub key: u8,
+    pub update_authority: [u8; 32],
+    pub mint: [u8; 32],
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl Metadata {
+    pub fn find_key(mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[SEED, &ID.to_bytes(), &mint.to_bytes()], &ID)
+    }
+
+    /// The remaining fields (creators, collection, ...) are ignored
+    pub fn unpack(mut data: &[u8]) -> Result<Self, ProgramError> {
+        let mut metadata =
+            Self::deserialize(&mut data).map_err(|_| ProgramError::InvalidAccountData)?;
+        if metadata.key != METADATA_KEY {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        for field in [&mut metadata.name, &mut metadata.symbol, &mut metadata.uri] {
+            let len = field.trim_end_matches('\0').len();
+            field.truncate(len);
+        }
+        Ok(metadata)
+    }
+}
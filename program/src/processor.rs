@@ -4,10 +4,51 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::instruction::RegistryInstruction;
+use crate::{instruction::RegistryInstruction, token_metadata::EMIT_DISCRIMINATOR};
 
+pub mod add_attester;
+pub mod allow_mint;
+pub mod approve_application;
+pub mod approve_claimant;
+pub mod attest;
+pub mod bid;
+pub mod cancel_listing;
+pub mod challenge;
+pub mod claim_entry;
+pub mod deny_mint;
+pub mod emit;
+pub mod finalize_listing;
 pub mod init;
+pub mod list_with_bond;
+pub mod migrate_central_state;
+pub mod migrate_entry;
+pub mod reclaim;
+pub mod refresh_extension_flags;
+pub mod refund_auction;
 pub mod register;
+pub mod register_v1;
+pub mod reject_application;
+pub mod release;
+pub mod remove_attester;
+pub mod renew;
+pub mod report;
+pub mod reserve;
+pub mod resolve_challenge;
+pub mod resolve_mint;
+pub mod resolve_report;
+pub mod resolve_ticker;
+pub mod revoke;
+pub mod set_bond_terms;
+pub mod set_fee_schedule;
+pub mod set_lease_terms;
+pub mod set_update_authority;
+pub mod set_verification_tier;
+pub mod settle_auction;
+pub mod start_auction;
+pub mod submit_application;
+pub mod sync_from_metaplex;
+pub mod update_metadata;
+pub mod withdraw_fees;
 
 pub struct Processor {}
 
@@ -18,6 +59,13 @@ impl Processor {
         instruction_data: &[u8],
     ) -> ProgramResult {
         msg!("Beginning processing");
+        // Instructions of the token-metadata interface are not part of the registry enum
+        if let Some(data) = instruction_data.strip_prefix(&EMIT_DISCRIMINATOR[..]) {
+            msg!("Instruction: Emit token metadata");
+            let params = emit::Params::try_from_slice(data)
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+            return emit::process(program_id, accounts, params);
+        }
         let instruction = RegistryInstruction::try_from_slice(instruction_data)
             .map_err(|_| ProgramError::InvalidInstructionData)?;
         msg!("Instruction unpacked");
@@ -28,9 +76,169 @@ impl Processor {
                 init::process(program_id, accounts, params)?;
             }
             RegistryInstruction::Register(params) => {
+                msg!("Instruction: Register (legacy)");
+                register_v1::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Reserve(params) => {
+                msg!("Instruction: Reserve");
+                reserve::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Release(params) => {
+                msg!("Instruction: Release");
+                release::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::DenyMint(params) => {
+                msg!("Instruction: Deny mint");
+                deny_mint::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::AllowMint(params) => {
+                msg!("Instruction: Allow mint");
+                allow_mint::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SetFeeSchedule(params) => {
+                msg!("Instruction: Set fee schedule");
+                set_fee_schedule::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::WithdrawFees(params) => {
+                msg!("Instruction: Withdraw fees");
+                withdraw_fees::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::StartAuction(params) => {
+                msg!("Instruction: Start auction");
+                start_auction::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Bid(params) => {
+                msg!("Instruction: Bid");
+                bid::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SettleAuction(params) => {
+                msg!("Instruction: Settle auction");
+                settle_auction::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SetLeaseTerms(params) => {
+                msg!("Instruction: Set lease terms");
+                set_lease_terms::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Renew(params) => {
+                msg!("Instruction: Renew");
+                renew::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Reclaim(params) => {
+                msg!("Instruction: Reclaim");
+                reclaim::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SubmitApplication(params) => {
+                msg!("Instruction: Submit application");
+                submit_application::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ApproveApplication(params) => {
+                msg!("Instruction: Approve application");
+                approve_application::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::RejectApplication(params) => {
+                msg!("Instruction: Reject application");
+                reject_application::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SetBondTerms(params) => {
+                msg!("Instruction: Set bond terms");
+                set_bond_terms::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ListWithBond(params) => {
+                msg!("Instruction: List with bond");
+                list_with_bond::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Challenge(params) => {
+                msg!("Instruction: Challenge");
+                challenge::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ResolveChallenge(params) => {
+                msg!("Instruction: Resolve challenge");
+                resolve_challenge::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::FinalizeListing(params) => {
+                msg!("Instruction: Finalize listing");
+                finalize_listing::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Report(params) => {
+                msg!("Instruction: Report");
+                report::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ResolveReport(params) => {
+                msg!("Instruction: Resolve report");
+                resolve_report::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::AddAttester(params) => {
+                msg!("Instruction: Add attester");
+                add_attester::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::RemoveAttester(params) => {
+                msg!("Instruction: Remove attester");
+                remove_attester::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Attest(params) => {
+                msg!("Instruction: Attest");
+                attest::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::Revoke(params) => {
+                msg!("Instruction: Revoke");
+                revoke::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SetVerificationTier(params) => {
+                msg!("Instruction: Set verification tier");
+                set_verification_tier::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SetUpdateAuthority(params) => {
+                msg!("Instruction: Set update authority");
+                set_update_authority::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::UpdateMetadata(params) => {
+                msg!("Instruction: Update metadata");
+                update_metadata::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ApproveClaimant(params) => {
+                msg!("Instruction: Approve claimant");
+                approve_claimant::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ClaimEntry(params) => {
+                msg!("Instruction: Claim entry");
+                claim_entry::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::RefreshExtensionFlags(params) => {
+                msg!("Instruction: Refresh extension flags");
+                refresh_extension_flags::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::SyncFromMetaplex(params) => {
+                msg!("Instruction: Sync from Metaplex");
+                sync_from_metaplex::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ResolveTicker(params) => {
+                msg!("Instruction: Resolve ticker");
+                resolve_ticker::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::ResolveMint(params) => {
+                msg!("Instruction: Resolve mint");
+                resolve_mint::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::MigrateCentralState(params) => {
+                msg!("Instruction: Migrate central state");
+                migrate_central_state::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::MigrateEntry(params) => {
+                msg!("Instruction: Migrate entry");
+                migrate_entry::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::RegisterV2(params) => {
                 msg!("Instruction: Register");
                 register::process(program_id, accounts, params)?;
             }
+            RegistryInstruction::RefundAuction(params) => {
+                msg!("Instruction: Refund auction");
+                refund_auction::process(program_id, accounts, params)?;
+            }
+            RegistryInstruction::CancelListing(params) => {
+                msg!("Instruction: Cancel listing");
+                cancel_listing::process(program_id, accounts, params)?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,107 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::Attester,
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub attester: Pubkey,
+    pub name: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    attester_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        _program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            attester_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.attester_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let (attester_key, nonce) = Attester::find_key(program_id, &params.attester);
+    check_account_key(
+        accounts.attester_account,
+        &attester_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let attester = Attester::new(params.attester.to_bytes(), params.name);
+    let signer_seeds: &[&[u8]] = &[Attester::SEED, &attester.attester, &[nonce]];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.attester_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        attester.try_to_vec().unwrap().len(),
+    )?;
+
+    attester.save(&mut accounts.attester_account.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{DeniedMint, TokenData, TokenStatus, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_name_account, check_signer, close_account,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub mint: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    fee_payer: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    name_service_program: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            fee_payer: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            name_service_program: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.denied_mint_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let (denied_mint_key, _) = DeniedMint::find_key(program_id, &mint);
+    check_account_key(
+        accounts.denied_mint_account,
+        &denied_mint_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    close_account(accounts.denied_mint_account, accounts.fee_payer);
+
+    // Lift the flag set when the mint was denylisted
+    if accounts.mint_name_account.data_len() == 0 {
+        return Ok(());
+    }
+    check_account_owner(
+        accounts.mint_name_account,
+        &spl_name_service::ID,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    if token_data.status != TokenStatus::Denylisted {
+        return Ok(());
+    }
+    token_data.status = TokenStatus::Active;
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        token_data.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    processor::register::{create_entry, EntryAccounts},
+    state::{Application, CentralState, Tag, TokenData, VerificationTier, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_bonded_listing, check_denied_mint,
+        check_mint_account, check_name_account, check_reserved_ticker, check_signer,
+        check_tombstone, close_account, get_extension_flags,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    admin: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    application_account: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    applicant: &'a AccountInfo<'b>,
+    mint_account: &'a AccountInfo<'b>,
+    legacy_ticker_name_account: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+    tombstone_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            admin: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            application_account: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            applicant: next_account_info(accounts_iter)?,
+            mint_account: next_account_info(accounts_iter)?,
+            legacy_ticker_name_account: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+            tombstone_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.root_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.application_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.admin)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.admin.key, params.signer_index)?;
+
+    let application =
+        Application::deserialize(&mut &accounts.application_account.data.borrow()[..])?;
+    if application.tag != Tag::Application {
+        msg!("Invalid application account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    check_account_key(
+        accounts.applicant,
+        &Pubkey::new(&application.applicant),
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    // The ticker or the mint could have been restricted or deleted since the submission
+    check_reserved_ticker(
+        program_id,
+        &application.ticker,
+        accounts.reserved_ticker_account,
+    )?;
+    check_bonded_listing(program_id, &application.ticker, accounts.listing_account)?;
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    check_tombstone(
+        program_id,
+        &application.ticker,
+        accounts.tombstone_account,
+        central_state.ticker_cooldown,
+    )?;
+    let mint = Pubkey::from_str(&application.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+    let (mint_state, token_program) = check_mint_account(&mint, accounts.mint_account)?;
+    if mint_state.decimals != application.decimals {
+        msg!("The decimals do not match the mint");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if accounts.ticker_name_account.data_len() != 0 {
+        msg!("Name account (ticker) is already initialized.");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    if accounts.mint_name_account.data_len() != 0 {
+        msg!("Name account (mint) is already initialized.");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    let hashed_ticker_name = check_name_account(
+        program_id,
+        &application.ticker,
+        accounts.ticker_name_account.key,
+    )?;
+    let hashed_mint_name = check_name_account(
+        program_id,
+        &application.mint,
+        accounts.mint_name_account.key,
+    )?;
+
+    let mut token_data = TokenData::new(
+        VerificationTier::Community,
+        application.name,
+        application.ticker,
+        mint.to_bytes(),
+        application.decimals,
+        application.website,
+        application.logo_uri,
+    );
+    token_data.token_program = token_program.to_bytes();
+    token_data.extension_flags = get_extension_flags(accounts.mint_account)?;
+
+    create_entry(
+        program_id,
+        &EntryAccounts {
+            name_service_program: accounts.name_service_program,
+            system_program: accounts.system_program,
+            fee_payer: accounts.admin,
+            ticker_name_account: accounts.ticker_name_account,
+            mint_name_account: accounts.mint_name_account,
+            root_name_account: accounts.root_name_account,
+            central_state: accounts.central_state,
+            legacy_ticker_name_account: accounts.legacy_ticker_name_account,
+            legacy_mint_name_account: accounts.legacy_mint_name_account,
+        },
+        hashed_ticker_name,
+        hashed_mint_name,
+        &token_data,
+    )?;
+
+    // The admin fronted the rent of the name records, the deposit goes back to the applicant
+    **accounts.application_account.lamports.borrow_mut() -= application.escrowed_rent;
+    **accounts.admin.lamports.borrow_mut() += application.escrowed_rent;
+    close_account(accounts.application_account, accounts.applicant);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::ClaimApproval,
+    utils::{check_account_key, check_signer, close_account},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub mint: Pubkey,
+    /// `None` withdraws the approval
+    pub claimant: Option<Pubkey>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    claim_approval_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        _program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            claim_approval_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let (claim_approval_key, nonce) = ClaimApproval::find_key(program_id, &params.mint);
+    check_account_key(
+        accounts.claim_approval_account,
+        &claim_approval_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let claimant = match params.claimant {
+        Some(claimant) => claimant,
+        None => {
+            if accounts.claim_approval_account.owner == program_id {
+                close_account(accounts.claim_approval_account, accounts.fee_payer);
+            }
+            return Ok(());
+        }
+    };
+
+    if accounts.claim_approval_account.data_len() == 0 {
+        let mint_bytes = params.mint.to_bytes();
+        let signer_seeds: &[&[u8]] = &[ClaimApproval::SEED, &mint_bytes, &[nonce]];
+        Cpi::create_account(
+            program_id,
+            accounts.system_program,
+            accounts.fee_payer,
+            accounts.claim_approval_account,
+            accounts.rent_sysvar_account,
+            signer_seeds,
+            ClaimApproval::LEN,
+        )?;
+    }
+
+    ClaimApproval::new(params.mint.to_bytes(), claimant.to_bytes())
+        .save(&mut accounts.claim_approval_account.data.borrow_mut());
+
+    Ok(())
+}
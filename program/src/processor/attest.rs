@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{self, Sysvar},
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{Attestation, AttestationKind, Attester, Tag},
+    utils::{check_account_key, check_account_owner, check_name_account, check_signer},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: String,
+    pub kind: AttestationKind,
+    /// Unix timestamp, zero for an attestation that does not expire
+    pub expiry: i64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    attester: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    attester_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    attestation_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            attester: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            attester_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            attestation_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.attester)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let (attester_key, _) = Attester::find_key(program_id, accounts.attester.key);
+    check_account_key(
+        accounts.attester_account,
+        &attester_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if accounts.attester_account.owner != program_id {
+        return Err(TokenRegistryError::AttesterNotRegistered.into());
+    }
+
+    // Only registered tokens can be attested to
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if params.expiry != 0 && params.expiry <= now {
+        msg!("The attestation would already be expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (attestation_key, nonce) = Attestation::find_key(program_id, &mint, accounts.attester.key);
+    check_account_key(
+        accounts.attestation_account,
+        &attestation_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    // Attesting again replaces the previous attestation
+    if accounts.attestation_account.data_len() == 0 {
+        let mint_bytes = mint.to_bytes();
+        let attester_bytes = accounts.attester.key.to_bytes();
+        let signer_seeds: &[&[u8]] = &[Attestation::SEED, &mint_bytes, &attester_bytes, &[nonce]];
+        Cpi::create_account(
+            program_id,
+            accounts.system_program,
+            accounts.attester,
+            accounts.attestation_account,
+            accounts.rent_sysvar_account,
+            signer_seeds,
+            Attestation::LEN,
+        )?;
+    }
+
+    Attestation {
+        tag: Tag::Attestation,
+        mint: mint.to_bytes(),
+        attester: accounts.attester.key.to_bytes(),
+        kind: params.kind,
+        issued_at: now,
+        expiry: params.expiry,
+    }
+    .save(&mut accounts.attestation_account.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{Auction, Tag},
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub amount: u64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    bidder: &'a AccountInfo<'b>,
+    auction_account: &'a AccountInfo<'b>,
+    previous_bidder: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            bidder: next_account_info(accounts_iter)?,
+            auction_account: next_account_info(accounts_iter)?,
+            previous_bidder: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.auction_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.bidder)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let mut auction = Auction::deserialize(&mut &accounts.auction_account.data.borrow()[..])?;
+    if auction.tag != Tag::Auction {
+        msg!("Invalid auction account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= auction.end_timestamp {
+        return Err(TokenRegistryError::AuctionEnded.into());
+    }
+    if params.amount < auction.min_bid || params.amount <= auction.highest_bid {
+        msg!(
+            "Bid must be at least {} and above {}",
+            auction.min_bid,
+            auction.highest_bid
+        );
+        return Err(TokenRegistryError::BidTooLow.into());
+    }
+
+    // Refund the outbid bidder from the escrow
+    if auction.highest_bid != 0 {
+        check_account_key(
+            accounts.previous_bidder,
+            &Pubkey::new(&auction.highest_bidder),
+            TokenRegistryError::InvalidKey,
+        )?;
+        **accounts.auction_account.lamports.borrow_mut() -= auction.highest_bid;
+        **accounts.previous_bidder.lamports.borrow_mut() += auction.highest_bid;
+    }
+
+    Cpi::transfer_lamports(
+        accounts.system_program,
+        accounts.bidder,
+        accounts.auction_account,
+        params.amount,
+        &[],
+    )?;
+
+    auction.highest_bid = params.amount;
+    auction.highest_bidder = accounts.bidder.key.to_bytes();
+    auction.save(&mut accounts.auction_account.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{BondedListing, Tag},
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+/// Withdraws a listing that is not challenged, e.g. when it can no longer be finalized because
+/// the ticker was reserved or registered or the mint was denylisted. The bond and the escrowed
+/// rent are refunded to the registrant
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {}
+
+struct Accounts<'a, 'b: 'a> {
+    registrant: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            registrant: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.listing_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.registrant)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let listing = BondedListing::deserialize(&mut &accounts.listing_account.data.borrow()[..])?;
+    if listing.tag != Tag::BondedListing {
+        msg!("Invalid listing account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    // Challenged listings are settled by `ResolveChallenge`
+    if listing.is_challenged() {
+        return Err(TokenRegistryError::AlreadyChallenged.into());
+    }
+    check_account_key(
+        accounts.registrant,
+        &Pubkey::new(&listing.registrant),
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    close_account(accounts.listing_account, accounts.registrant);
+
+    Ok(())
+}
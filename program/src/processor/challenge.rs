@@ -0,0 +1,100 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{BondedListing, Tag},
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    challenger: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            challenger: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.listing_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.challenger)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let mut listing = BondedListing::deserialize(&mut &accounts.listing_account.data.borrow()[..])?;
+    if listing.tag != Tag::BondedListing {
+        msg!("Invalid listing account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    if Clock::get()?.unix_timestamp >= listing.challenge_end {
+        return Err(TokenRegistryError::ChallengeWindowClosed.into());
+    }
+    if listing.is_challenged() {
+        return Err(TokenRegistryError::AlreadyChallenged.into());
+    }
+    if accounts.challenger.key.to_bytes() == listing.registrant {
+        msg!("The registrant cannot challenge its own listing");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The counter-bond matches the bond of the registrant
+    Cpi::transfer_lamports(
+        accounts.system_program,
+        accounts.challenger,
+        accounts.listing_account,
+        listing.bond,
+        &[],
+    )?;
+
+    listing.challenger = accounts.challenger.key.to_bytes();
+    listing.counter_bond = listing.bond;
+    listing.save(&mut accounts.listing_account.data.borrow_mut());
+
+    Ok(())
+}
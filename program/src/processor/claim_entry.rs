@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{ClaimApproval, Tag, TokenData, VerificationTier, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_bonded_listing, check_mint_authority,
+        check_name_account, check_signer, close_account,
+    },
+};
+
+/// Verified entries can be claimed once, by the approved claimant or the mint authority. The
+/// claim approval is consumed and its rent goes to the claimant
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    claimant: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    mint_account: &'a AccountInfo<'b>,
+    claim_approval_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            claimant: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint_account: next_account_info(accounts_iter)?,
+            claim_approval_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.claimant)?;
+
+        Ok(accounts)
+    }
+}
+
+/// Whether an admin listed the claimant for this mint
+fn is_approved_claimant(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    claimant: &Pubkey,
+    claim_approval_account: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    let (claim_approval_key, _) = ClaimApproval::find_key(program_id, mint);
+    check_account_key(
+        claim_approval_account,
+        &claim_approval_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if claim_approval_account.owner != program_id {
+        return Ok(false);
+    }
+    let claim_approval =
+        ClaimApproval::deserialize(&mut &claim_approval_account.data.borrow()[..])?;
+    Ok(claim_approval.tag == Tag::ClaimApproval && claim_approval.claimant == claimant.to_bytes())
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    // Either an admin listed the claimant or it is the mint authority
+    if !is_approved_claimant(
+        program_id,
+        &mint,
+        accounts.claimant.key,
+        accounts.claim_approval_account,
+    )? {
+        check_mint_authority(&mint, accounts.mint_account, accounts.claimant)?;
+    }
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    if token_data.claim_slot != 0 {
+        return Err(TokenRegistryError::AlreadyClaimed.into());
+    }
+    if token_data.tier != VerificationTier::Verified {
+        msg!("Only verified entries can be claimed");
+        return Err(ProgramError::InvalidArgument);
+    }
+    check_bonded_listing(program_id, &token_data.ticker, accounts.listing_account)?;
+    token_data.update_authority = accounts.claimant.key.to_bytes();
+    token_data.claim_slot = Clock::get()?.slot;
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        token_data.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    if accounts.claim_approval_account.owner == program_id {
+        close_account(accounts.claim_approval_account, accounts.claimant);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{DeniedMint, TokenData, TokenStatus, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_legacy_name_account, check_name_account,
+        check_signer,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+/// Flags the registered entry of the mint. Legacy entries cannot be flagged and must be migrated
+/// with `MigrateEntry` beforehand
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub mint: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    name_service_program: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            name_service_program: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.denied_mint_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+    check_legacy_name_account(&params.mint, accounts.legacy_mint_name_account)?;
+
+    let (denied_mint_key, nonce) = DeniedMint::find_key(program_id, &mint);
+    check_account_key(
+        accounts.denied_mint_account,
+        &denied_mint_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let mint_bytes = mint.to_bytes();
+    let signer_seeds: &[&[u8]] = &[DeniedMint::SEED, &mint_bytes, &[nonce]];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.denied_mint_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        DeniedMint::LEN,
+    )?;
+
+    DeniedMint::new(mint_bytes).save(&mut accounts.denied_mint_account.data.borrow_mut());
+
+    // Flag the existing entry, if any
+    if accounts.mint_name_account.data_len() == 0 {
+        return Ok(());
+    }
+    check_account_owner(
+        accounts.mint_name_account,
+        &spl_name_service::ID,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    token_data.status = TokenStatus::Denylisted;
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        token_data.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{check_registry_record, TokenData},
+    token_metadata::TokenMetadata,
+    utils::{check_account_owner, check_name_account},
+};
+
+/// `Emit` of the token-metadata interface, returns the `[start, end)` slice of the serialized metadata
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    mint_name_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        _program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            mint_name_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_registry_record(program_id, &accounts.mint_name_account.data.borrow())?;
+
+    let token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    // Ticker records share the header, make sure this is the record of the mint
+    check_name_account(
+        program_id,
+        &Pubkey::new(&token_data.mint).to_string(),
+        accounts.mint_name_account.key,
+    )?;
+
+    let metadata = TokenMetadata::from(token_data).try_to_vec().unwrap();
+    let start = params.start.unwrap_or(0) as usize;
+    let end = params
+        .end
+        .map(|end| end as usize)
+        .unwrap_or_else(|| metadata.len());
+    set_return_data(metadata.get(start..end).unwrap_or(&[]));
+
+    Ok(())
+}
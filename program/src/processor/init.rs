@@ -88,7 +88,7 @@ pub(crate) fn process(
         CentralState::LEN,
     )?;
 
-    let state = CentralState { signer_nonce };
+    let state = CentralState::new(signer_nonce);
     state.pack_into_slice(&mut accounts.state_account.data.borrow_mut());
 
     Ok(())
@@ -0,0 +1,195 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{self, Sysvar},
+};
+
+use borsh::BorshSerialize;
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    processor::register::entry_rent,
+    state::{BondedListing, CentralState, Tag, TokenData, VerificationTier},
+    utils::{
+        check_account_key, check_account_owner, check_denied_mint, check_normalized_ticker,
+        check_reserved_ticker, check_signer, get_hashed_name, normalize_ticker, pay_fee,
+    },
+};
+
+/// The registration payload, `signer_index` must be `None`. The registration fee is charged when
+/// listing and is not refunded, the bond is refunded on finalization or cancellation
+pub use crate::processor::register::Params;
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    registrant: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    fee_source: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            registrant: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            fee_source: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.listing_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.registrant)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    if params.signer_index.is_some() || params.update_authority.is_some() {
+        msg!("Bonded listings cannot use an admin signer index or set an update authority");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    if central_state.registration_bond == 0 {
+        return Err(TokenRegistryError::BondedListingDisabled.into());
+    }
+
+    check_normalized_ticker(&params.ticker)?;
+    check_reserved_ticker(program_id, &params.ticker, accounts.reserved_ticker_account)?;
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (listing_key, nonce) = BondedListing::find_key(program_id, &normalized_ticker);
+    check_account_key(
+        accounts.listing_account,
+        &listing_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let registration_fee = central_state.registration_fee(&normalized_ticker);
+    if registration_fee != 0 {
+        pay_fee(
+            program_id,
+            &central_state,
+            accounts.system_program,
+            accounts.spl_token_program,
+            accounts.registrant,
+            accounts.fee_source,
+            accounts.treasury,
+            registration_fee,
+        )?;
+    }
+
+    let escrowed_rent = entry_rent(&TokenData::new(
+        VerificationTier::Community,
+        params.name.clone(),
+        params.ticker.clone(),
+        mint.to_bytes(),
+        params.decimals,
+        params.website.clone(),
+        params.logo_uri.clone(),
+    ))?;
+
+    let listing = BondedListing {
+        tag: Tag::BondedListing,
+        registrant: accounts.registrant.key.to_bytes(),
+        bond: central_state.registration_bond,
+        escrowed_rent,
+        challenge_end: Clock::get()?.unix_timestamp + central_state.challenge_window,
+        challenger: [0; 32],
+        counter_bond: 0,
+        ticker: params.ticker,
+        name: params.name,
+        mint: params.mint,
+        decimals: params.decimals,
+        website: params.website,
+        logo_uri: params.logo_uri,
+    };
+
+    let hashed_ticker = get_hashed_name(&normalized_ticker);
+    let signer_seeds: &[&[u8]] = &[BondedListing::SEED, &hashed_ticker, &[nonce]];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.registrant,
+        accounts.listing_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        listing.try_to_vec().unwrap().len(),
+    )?;
+
+    listing.save(&mut accounts.listing_account.data.borrow_mut());
+
+    // The listing account is the vault of the bond
+    Cpi::transfer_lamports(
+        accounts.system_program,
+        accounts.registrant,
+        accounts.listing_account,
+        listing.bond + listing.escrowed_rent,
+        &[],
+    )?;
+
+    Ok(())
+}
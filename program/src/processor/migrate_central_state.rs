@@ -0,0 +1,121 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program, sysvar,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::CentralState,
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+/// Permissionless, grows a central state created by an earlier version of the program to the
+/// current layout. Fields are only ever appended, so the existing data is kept as the prefix of
+/// the new layout and the appended fields take their `CentralState::new` defaults
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.central_state,
+            &CentralState::find_key(program_id).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let current_len = accounts.central_state.data_len();
+    if current_len >= CentralState::LEN {
+        msg!("The central state is already migrated");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = vec![0; CentralState::LEN];
+    {
+        let current_data = accounts.central_state.data.borrow();
+        CentralState::new(current_data[0]).pack_into_slice(&mut data);
+        data[..current_len].copy_from_slice(&current_data);
+    }
+
+    let rent = Rent::from_account_info(accounts.rent_sysvar_account)?;
+    let missing_lamports = rent
+        .minimum_balance(CentralState::LEN)
+        .saturating_sub(accounts.central_state.lamports());
+    if missing_lamports > 0 {
+        Cpi::transfer_lamports(
+            accounts.system_program,
+            accounts.fee_payer,
+            accounts.central_state,
+            missing_lamports,
+            &[],
+        )?;
+    }
+
+    accounts.central_state.realloc(CentralState::LEN, false)?;
+    accounts
+        .central_state
+        .data
+        .borrow_mut()
+        .copy_from_slice(&data);
+
+    Ok(())
+}
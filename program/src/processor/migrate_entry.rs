@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    derivation::{derive_legacy_mint_account, derive_legacy_ticker_account},
+    error::TokenRegistryError,
+    processor::register::{write_entry, EntryAccounts},
+    state::{TickerRecord, TokenRecord, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_denied_mint, check_mint_account,
+        check_name_account, check_normalized_ticker, check_signer, get_extension_flags,
+        normalize_ticker,
+    },
+};
+
+/// Permissionless, re-issues a legacy entry at the addresses derived with the registry class
+/// and in the current layout. The legacy records are deleted and their rent refunds the fee
+/// payer, who funds the new records. Legacy tickers were stored as given, they are re-issued
+/// under their normalized form. Legacy tickers have no lease and denylisted mints are not
+/// re-issued
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub ticker: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    legacy_ticker_name_account: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    mint_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            legacy_ticker_name_account: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.legacy_ticker_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.legacy_mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.root_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    check_account_key(
+        accounts.legacy_ticker_name_account,
+        &derive_legacy_ticker_account(&params.ticker),
+        TokenRegistryError::InvalidNameProvided,
+    )?;
+    let ticker_record = TickerRecord::from_account_data(
+        program_id,
+        &accounts.legacy_ticker_name_account.data.borrow(),
+    )?;
+    let mint = Pubkey::new(&ticker_record.data.mint);
+    check_account_key(
+        accounts.legacy_mint_name_account,
+        &derive_legacy_mint_account(&mint),
+        TokenRegistryError::InvalidNameProvided,
+    )?;
+    let token_record = TokenRecord::from_account_data(
+        program_id,
+        &accounts.legacy_mint_name_account.data.borrow(),
+    )?;
+    if !ticker_record.is_legacy() || !token_record.is_legacy() {
+        msg!("The entry is already migrated");
+        return Err(TokenRegistryError::InvalidRecordHeader.into());
+    }
+    if token_record.data.ticker != params.ticker {
+        msg!("The legacy records do not belong to the same entry");
+        return Err(TokenRegistryError::InvalidRecordData.into());
+    }
+
+    let ticker = normalize_ticker(&params.ticker);
+    check_normalized_ticker(&ticker)?;
+    let hashed_ticker_name =
+        check_name_account(program_id, &ticker, accounts.ticker_name_account.key)?;
+    let hashed_mint_name = check_name_account(
+        program_id,
+        &mint.to_string(),
+        accounts.mint_name_account.key,
+    )?;
+
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+    let (_, token_program) = check_mint_account(&mint, accounts.mint_account)?;
+    let mut token_data = token_record.data;
+    token_data.ticker = ticker;
+    token_data.token_program = token_program.to_bytes();
+    token_data.extension_flags = get_extension_flags(accounts.mint_account)?;
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+    for legacy_name_account in [
+        accounts.legacy_ticker_name_account,
+        accounts.legacy_mint_name_account,
+    ] {
+        Cpi::delete_name_account(
+            accounts.name_service_program,
+            legacy_name_account,
+            accounts.central_state,
+            accounts.fee_payer,
+            central_state_signer_seeds,
+        )?;
+    }
+
+    write_entry(
+        program_id,
+        &EntryAccounts {
+            name_service_program: accounts.name_service_program,
+            system_program: accounts.system_program,
+            fee_payer: accounts.fee_payer,
+            ticker_name_account: accounts.ticker_name_account,
+            mint_name_account: accounts.mint_name_account,
+            root_name_account: accounts.root_name_account,
+            central_state: accounts.central_state,
+            legacy_ticker_name_account: accounts.legacy_ticker_name_account,
+            legacy_mint_name_account: accounts.legacy_mint_name_account,
+        },
+        hashed_ticker_name,
+        hashed_mint_name,
+        &token_data,
+        0,
+    )
+}
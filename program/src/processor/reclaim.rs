@@ -0,0 +1,160 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{self, Sysvar},
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{CentralState, Mint, Tombstone},
+    utils::{
+        check_account_key, check_account_owner, check_name_account, check_signer, get_hashed_name,
+        normalize_ticker,
+    },
+};
+
+/// Permissionless, deletes the ticker record of a ticker whose lease ended more than the grace
+/// period ago. The mint record is kept, the rent of the ticker record goes to the treasury and
+/// the caller funds the tombstone
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub ticker: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    tombstone_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            tombstone_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.treasury,
+            &CentralState::find_treasury_key(program_id, &system_program::ID).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.ticker_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.ticker, accounts.ticker_name_account.key)?;
+
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (tombstone_key, tombstone_nonce) = Tombstone::find_key(program_id, &normalized_ticker);
+    check_account_key(
+        accounts.tombstone_account,
+        &tombstone_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let ticker_record = Mint::from_name_account(accounts.ticker_name_account)?;
+    if ticker_record.expiry == 0 {
+        return Err(TokenRegistryError::TickerHasNoLease.into());
+    }
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let reclaimable_at = ticker_record.expiry + central_state.grace_period;
+    if Clock::get()?.unix_timestamp < reclaimable_at {
+        msg!("The ticker can be reclaimed from {}", reclaimable_at);
+        return Err(TokenRegistryError::LeaseNotExpired.into());
+    }
+
+    let central_state_signer_seeds: &[&[u8]] =
+        &[&program_id.to_bytes(), &[central_state.signer_nonce]];
+    Cpi::delete_name_account(
+        accounts.name_service_program,
+        accounts.ticker_name_account,
+        accounts.central_state,
+        accounts.treasury,
+        central_state_signer_seeds,
+    )?;
+
+    // Keep track of the deletion so that the ticker is not reused right away
+    if accounts.tombstone_account.data_len() == 0 {
+        let hashed_ticker = get_hashed_name(&normalized_ticker);
+        let tombstone_signer_seeds: &[&[u8]] =
+            &[Tombstone::SEED, &hashed_ticker, &[tombstone_nonce]];
+        Cpi::create_account(
+            program_id,
+            accounts.system_program,
+            accounts.fee_payer,
+            accounts.tombstone_account,
+            accounts.rent_sysvar_account,
+            tombstone_signer_seeds,
+            Tombstone::LEN,
+        )?;
+    }
+
+    Tombstone::new(Clock::get()?.slot, ticker_record.mint)
+        .save(&mut accounts.tombstone_account.data.borrow_mut());
+
+    Ok(())
+}
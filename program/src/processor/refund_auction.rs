@@ -0,0 +1,94 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{Auction, CentralState, Tag},
+    utils::{check_account_key, check_account_owner, close_account},
+};
+
+/// Permissionless, refunds the winning bid of an auction that was not settled before its
+/// settlement deadline. The ticker stays reserved and the rent of the auction goes to the
+/// treasury
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {}
+
+struct Accounts<'a, 'b: 'a> {
+    auction_account: &'a AccountInfo<'b>,
+    highest_bidder: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            auction_account: next_account_info(accounts_iter)?,
+            highest_bidder: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.treasury,
+            &CentralState::find_treasury_key(program_id, &system_program::ID).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.auction_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let auction = Auction::deserialize(&mut &accounts.auction_account.data.borrow()[..])?;
+    if auction.tag != Tag::Auction {
+        msg!("Invalid auction account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    if Clock::get()?.unix_timestamp < auction.settlement_deadline() {
+        msg!(
+            "The winning bidder can settle the auction until {}",
+            auction.settlement_deadline()
+        );
+        return Err(TokenRegistryError::AuctionNotEnded.into());
+    }
+
+    if auction.highest_bid != 0 {
+        check_account_key(
+            accounts.highest_bidder,
+            &Pubkey::new(&auction.highest_bidder),
+            TokenRegistryError::InvalidKey,
+        )?;
+        **accounts.auction_account.lamports.borrow_mut() -= auction.highest_bid;
+        **accounts.highest_bidder.lamports.borrow_mut() += auction.highest_bid;
+    }
+    close_account(accounts.auction_account, accounts.treasury);
+
+    Ok(())
+}
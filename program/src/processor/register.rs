@@ -2,9 +2,11 @@ use std::str::FromStr;
 
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
     rent::Rent,
     system_program,
@@ -16,8 +18,14 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use crate::{
     cpi::Cpi,
     error::TokenRegistryError,
-    state::{Mint, TokenData, TOKEN_TLD},
-    utils::{check_account_key, check_account_owner, check_name_account, check_signer},
+    state::{CentralState, Mint, TokenData, VerificationTier, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_auction, check_bonded_listing,
+        check_denied_mint, check_legacy_name_account, check_metaplex_metadata,
+        check_metaplex_symbol, check_mint_account, check_mint_authority, check_name_account,
+        check_normalized_ticker, check_reserved_ticker, check_signer, check_tombstone,
+        get_extension_flags, normalize_ticker, pay_fee,
+    },
 };
 
 #[cfg(not(feature = "test-bpf"))]
@@ -25,26 +33,42 @@ use crate::utils::check_registrar_signer;
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct Params {
-    pub signer_index: usize,
+    /// Admin registrations are free and verified. Others pay the registration fee and must
+    /// be signed by the mint authority
+    pub signer_index: Option<usize>,
     pub ticker: String,
     pub name: String,
     pub mint: String,
     pub decimals: u8,
     pub website: String,
     pub logo_uri: String,
+    /// Only admins can delegate metadata updates
+    pub update_authority: Option<Pubkey>,
 }
 
 impl<'a, 'b: 'a> Params {
     fn parse_params(
+        program_id: &Pubkey,
         params: &Params,
         accounts: &Accounts,
     ) -> Result<(Vec<u8>, Vec<u8>), ProgramError> {
         #[cfg(not(feature = "test-bpf"))]
-        check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+        if let Some(signer_index) = params.signer_index {
+            check_registrar_signer(accounts.fee_payer.key, signer_index)?;
+        }
+
+        check_normalized_ticker(&params.ticker)?;
+        check_reserved_ticker(program_id, &params.ticker, accounts.reserved_ticker_account)?;
+        check_bonded_listing(program_id, &params.ticker, accounts.listing_account)?;
+        check_auction(program_id, &params.ticker, accounts.auction_account)?;
+
+        let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+        check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
 
         let hashed_ticker_name =
-            check_name_account(&params.ticker, accounts.ticker_name_account.key)?;
-        let hashed_mint_name = check_name_account(&params.mint, accounts.mint_name_account.key)?;
+            check_name_account(program_id, &params.ticker, accounts.ticker_name_account.key)?;
+        let hashed_mint_name =
+            check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
 
         Ok((hashed_ticker_name, hashed_mint_name))
     }
@@ -59,6 +83,18 @@ struct Accounts<'a, 'b: 'a> {
     mint_name_account: &'a AccountInfo<'b>,
     root_name_account: &'a AccountInfo<'b>,
     central_state: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    fee_source: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+    tombstone_account: &'a AccountInfo<'b>,
+    mint_account: &'a AccountInfo<'b>,
+    legacy_ticker_name_account: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+    auction_account: &'a AccountInfo<'b>,
+    metaplex_metadata_account: Option<&'a AccountInfo<'b>>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, 'b> {
@@ -76,6 +112,18 @@ impl<'a, 'b: 'a> Accounts<'a, 'b> {
             mint_name_account: next_account_info(accounts_iter)?,
             root_name_account: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            fee_source: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            tombstone_account: next_account_info(accounts_iter)?,
+            mint_account: next_account_info(accounts_iter)?,
+            legacy_ticker_name_account: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+            auction_account: next_account_info(accounts_iter)?,
+            metaplex_metadata_account: next_account_info(accounts_iter).ok(),
         };
 
         if accounts.ticker_name_account.data_len() != 0 {
@@ -109,6 +157,11 @@ impl<'a, 'b: 'a> Accounts<'a, 'b> {
             &Pubkey::from_str(TOKEN_TLD).unwrap(),
             TokenRegistryError::InvalidTld,
         )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
 
         // Ownership checks
         check_account_owner(
@@ -129,32 +182,183 @@ impl<'a, 'b: 'a> Accounts<'a, 'b> {
     }
 }
 
+fn pay_registration_fee(program_id: &Pubkey, accounts: &Accounts, ticker: &str) -> ProgramResult {
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let registration_fee = central_state.registration_fee(&normalize_ticker(ticker));
+    if registration_fee == 0 {
+        msg!("Registration fees are not configured");
+        return Err(TokenRegistryError::SelfRegistrationDisabled.into());
+    }
+
+    pay_fee(
+        program_id,
+        &central_state,
+        accounts.system_program,
+        accounts.spl_token_program,
+        accounts.fee_payer,
+        accounts.fee_source,
+        accounts.treasury,
+        registration_fee,
+    )
+}
+
 pub(crate) fn process(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     params: Params,
 ) -> ProgramResult {
     let accounts = Accounts::parse_accounts(program_id, accounts)?;
-    let (hashed_ticker_name, hashed_mint_name) = Params::parse_params(&params, &accounts)?;
+    let (hashed_ticker_name, hashed_mint_name) =
+        Params::parse_params(program_id, &params, &accounts)?;
+
+    // Admins register on behalf of projects, others must control the mint
+    let mint = Pubkey::from_str(&params.mint).unwrap();
+    let (mint_state, token_program) = if params.signer_index.is_some() {
+        check_mint_account(&mint, accounts.mint_account)?
+    } else {
+        check_mint_authority(&mint, accounts.mint_account, accounts.fee_payer)?
+    };
+    if mint_state.decimals != params.decimals {
+        msg!("The decimals do not match the mint");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Admin registrations override the cooldown of deleted tickers
+    let tier = if params.signer_index.is_some() {
+        VerificationTier::Verified
+    } else {
+        if params.update_authority.is_some() {
+            msg!("Only admins can set an update authority");
+            return Err(ProgramError::InvalidArgument);
+        }
 
-    let mint_as_bytes = Pubkey::from_str(params.mint.as_str()).unwrap().to_bytes();
+        let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+        check_tombstone(
+            program_id,
+            &params.ticker,
+            accounts.tombstone_account,
+            central_state.ticker_cooldown,
+        )?;
+        pay_registration_fee(program_id, &accounts, &params.ticker)?;
+        VerificationTier::Unverified
+    };
+
+    // The Metaplex metadata fills in the name when it is left empty, its uri points to a JSON
+    // document and not to a logo so it is not used
+    let mut name = params.name;
+    if let Some(metadata_account) = accounts.metaplex_metadata_account {
+        let metadata = check_metaplex_metadata(&mint, metadata_account)?;
+        check_metaplex_symbol(&metadata, &params.ticker)?;
+        if name.is_empty() {
+            name = metadata.name;
+        }
+    }
 
     // Token data
-    let token_data = TokenData::new(
-        params.name,
+    let mut token_data = TokenData::new(
+        tier,
+        name,
         params.ticker,
-        mint_as_bytes,
+        mint.to_bytes(),
         params.decimals,
         params.website,
         params.logo_uri,
+    );
+    if let Some(update_authority) = params.update_authority {
+        token_data.update_authority = update_authority.to_bytes();
+    }
+    token_data.token_program = token_program.to_bytes();
+    token_data.extension_flags = get_extension_flags(accounts.mint_account)?;
+
+    create_entry(
+        program_id,
+        &EntryAccounts {
+            name_service_program: accounts.name_service_program,
+            system_program: accounts.system_program,
+            fee_payer: accounts.fee_payer,
+            ticker_name_account: accounts.ticker_name_account,
+            mint_name_account: accounts.mint_name_account,
+            root_name_account: accounts.root_name_account,
+            central_state: accounts.central_state,
+            legacy_ticker_name_account: accounts.legacy_ticker_name_account,
+            legacy_mint_name_account: accounts.legacy_mint_name_account,
+        },
+        hashed_ticker_name,
+        hashed_mint_name,
+        &token_data,
+    )
+}
+
+/// Lamports needed to create the name records of a registry entry
+pub(crate) fn entry_rent(token_data: &TokenData) -> Result<u64, ProgramError> {
+    let rent = Rent::get()?;
+    let mint_data_len = Mint::new(token_data.mint, 0).try_to_vec().unwrap().len();
+    Ok(rent.minimum_balance(token_data.record_space()) + rent.minimum_balance(mint_data_len))
+}
+
+/// Accounts needed to write the name records of a registry entry
+pub(crate) struct EntryAccounts<'a, 'b: 'a> {
+    pub name_service_program: &'a AccountInfo<'b>,
+    pub system_program: &'a AccountInfo<'b>,
+    pub fee_payer: &'a AccountInfo<'b>,
+    pub ticker_name_account: &'a AccountInfo<'b>,
+    pub mint_name_account: &'a AccountInfo<'b>,
+    pub root_name_account: &'a AccountInfo<'b>,
+    pub central_state: &'a AccountInfo<'b>,
+    pub legacy_ticker_name_account: &'a AccountInfo<'b>,
+    pub legacy_mint_name_account: &'a AccountInfo<'b>,
+}
+
+/// Creates the ticker and mint name accounts of a registry entry, the ticker is leased
+/// when leases are enabled. The central state owns the records and is their class.
+/// The name accounts must have been checked beforehand, their legacy class-less counterparts
+/// are checked here so that no path can register a name twice
+pub(crate) fn create_entry(
+    program_id: &Pubkey,
+    accounts: &EntryAccounts,
+    hashed_ticker_name: Vec<u8>,
+    hashed_mint_name: Vec<u8>,
+    token_data: &TokenData,
+) -> ProgramResult {
+    check_normalized_ticker(&token_data.ticker)?;
+    check_legacy_name_account(&token_data.ticker, accounts.legacy_ticker_name_account)?;
+    check_legacy_name_account(
+        &Pubkey::new(&token_data.mint).to_string(),
+        accounts.legacy_mint_name_account,
+    )?;
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let expiry = if central_state.lease_duration == 0 {
+        0
+    } else {
+        Clock::get()?.unix_timestamp + central_state.lease_duration
+    };
+
+    write_entry(
+        program_id,
+        accounts,
+        hashed_ticker_name,
+        hashed_mint_name,
+        token_data,
+        expiry,
     )
-    .try_to_vec()
-    .unwrap();
+}
 
-    // Mint data
-    let mint_data = Mint::new(mint_as_bytes).try_to_vec().unwrap();
+/// Creates the name records of a registry entry whose ticker lease ends at `expiry`,
+/// zero for no lease. The legacy name accounts are not checked
+pub(crate) fn write_entry(
+    program_id: &Pubkey,
+    accounts: &EntryAccounts,
+    hashed_ticker_name: Vec<u8>,
+    hashed_mint_name: Vec<u8>,
+    token_data: &TokenData,
+    expiry: i64,
+) -> ProgramResult {
+    let mint_data = Mint::new(token_data.mint, expiry).try_to_vec().unwrap();
+    let token_data_space = token_data.record_space();
+    let token_data = token_data.try_to_vec().unwrap();
 
-    let lamports_token_data = Rent::get()?.minimum_balance(token_data.len());
+    let lamports_token_data = Rent::get()?.minimum_balance(token_data_space);
     let lamports_mint_data = Rent::get()?.minimum_balance(mint_data.len());
 
     let central_state_nonce = accounts.central_state.data.borrow()[0];
@@ -167,6 +371,7 @@ pub(crate) fn process(
         accounts.ticker_name_account,
         accounts.fee_payer,
         accounts.central_state,
+        Some(accounts.central_state),
         accounts.root_name_account,
         accounts.central_state,
         hashed_ticker_name,
@@ -182,11 +387,12 @@ pub(crate) fn process(
         accounts.mint_name_account,
         accounts.fee_payer,
         accounts.central_state,
+        Some(accounts.central_state),
         accounts.root_name_account,
         accounts.central_state,
         hashed_mint_name,
         lamports_token_data,
-        token_data.len() as u32,
+        token_data_space as u32,
         central_state_signer_seeds,
     )?;
 
@@ -0,0 +1,234 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{LegacyTokenData, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_auction, check_bonded_listing,
+        check_denied_mint, check_legacy_name_account, check_name_account, check_normalized_ticker,
+        check_reserved_ticker, check_signer, get_hashed_name,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+/// Admin registration of the first version of the program, kept for the clients built
+/// against it. The entry is written in the legacy layout at the legacy addresses and can be
+/// migrated with `MigrateEntry`. New clients should use `RegisterV2`, whose checks apply here as well
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub ticker: String,
+    pub name: String,
+    pub mint: String,
+    pub decimals: u8,
+    pub website: String,
+    pub logo_uri: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    legacy_ticker_name_account: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+    auction_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            legacy_ticker_name_account: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+            auction_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &solana_program::sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.root_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+/// The names cannot be registered at the legacy addresses when they exist at the current ones
+fn check_current_name_account(
+    program_id: &Pubkey,
+    name: &str,
+    name_account: &AccountInfo,
+) -> ProgramResult {
+    check_name_account(program_id, name, name_account.key)?;
+    if name_account.data_len() != 0 {
+        msg!("{} is already registered", name);
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    Ok(())
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    check_normalized_ticker(&params.ticker)?;
+    check_reserved_ticker(program_id, &params.ticker, accounts.reserved_ticker_account)?;
+    check_bonded_listing(program_id, &params.ticker, accounts.listing_account)?;
+    check_auction(program_id, &params.ticker, accounts.auction_account)?;
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+    check_legacy_name_account(&params.ticker, accounts.legacy_ticker_name_account)?;
+    check_legacy_name_account(&params.mint, accounts.legacy_mint_name_account)?;
+    check_current_name_account(program_id, &params.ticker, accounts.ticker_name_account)?;
+    check_current_name_account(program_id, &params.mint, accounts.mint_name_account)?;
+    let hashed_ticker_name = get_hashed_name(&params.ticker);
+    let hashed_mint_name = get_hashed_name(&params.mint);
+
+    // Token data
+    let token_data = LegacyTokenData {
+        name: params.name,
+        ticker: params.ticker,
+        mint: mint.to_bytes(),
+        decimals: params.decimals,
+        website: params.website,
+        logo_uri: params.logo_uri,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    // Mint data
+    let mint_data = mint.to_bytes().to_vec();
+
+    let lamports_token_data = Rent::get()?.minimum_balance(token_data.len());
+    let lamports_mint_data = Rent::get()?.minimum_balance(mint_data.len());
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    //// Create ticker registry
+    Cpi::create_name_account(
+        accounts.name_service_program,
+        accounts.system_program,
+        accounts.legacy_ticker_name_account,
+        accounts.fee_payer,
+        accounts.central_state,
+        None,
+        accounts.root_name_account,
+        accounts.central_state,
+        hashed_ticker_name,
+        lamports_mint_data,
+        mint_data.len() as u32,
+        central_state_signer_seeds,
+    )?;
+
+    //// Create mint registry
+    Cpi::create_name_account(
+        accounts.name_service_program,
+        accounts.system_program,
+        accounts.legacy_mint_name_account,
+        accounts.fee_payer,
+        accounts.central_state,
+        None,
+        accounts.root_name_account,
+        accounts.central_state,
+        hashed_mint_name,
+        lamports_token_data,
+        token_data.len() as u32,
+        central_state_signer_seeds,
+    )?;
+
+    // Serialization
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.legacy_ticker_name_account,
+        accounts.central_state,
+        mint_data,
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.legacy_mint_name_account,
+        accounts.central_state,
+        token_data,
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
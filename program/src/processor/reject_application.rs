@@ -0,0 +1,105 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{Application, CentralState, Tag},
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    admin: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    application_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    applicant: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            admin: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            application_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            applicant: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.treasury,
+            &CentralState::find_treasury_key(program_id, &system_program::ID).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.application_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.admin)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.admin.key, params.signer_index)?;
+
+    let application =
+        Application::deserialize(&mut &accounts.application_account.data.borrow()[..])?;
+    if application.tag != Tag::Application {
+        msg!("Invalid application account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    check_account_key(
+        accounts.applicant,
+        &Pubkey::new(&application.applicant),
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    // The rejection fee is taken from the deposit, everything else is refunded
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let fee = central_state.rejection_fee.min(application.deposit);
+    **accounts.application_account.lamports.borrow_mut() -= fee;
+    **accounts.treasury.lamports.borrow_mut() += fee;
+    close_account(accounts.application_account, accounts.applicant);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::ReservedTicker,
+    utils::{
+        check_account_key, check_account_owner, check_signer, close_account, normalize_ticker,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub ticker: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    fee_payer: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            fee_payer: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.reserved_ticker_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let (reserved_ticker_key, _) =
+        ReservedTicker::find_key(program_id, &normalize_ticker(&params.ticker));
+    check_account_key(
+        accounts.reserved_ticker_account,
+        &reserved_ticker_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    close_account(accounts.reserved_ticker_account, accounts.fee_payer);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::Attester,
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+/// Attestations of a removed attester are no longer considered valid
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub attester: Pubkey,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    fee_payer: &'a AccountInfo<'b>,
+    attester_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            fee_payer: next_account_info(accounts_iter)?,
+            attester_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.attester_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    let (attester_key, _) = Attester::find_key(program_id, &params.attester);
+    check_account_key(
+        accounts.attester_account,
+        &attester_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    close_account(accounts.attester_account, accounts.fee_payer);
+
+    Ok(())
+}
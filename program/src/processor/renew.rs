@@ -0,0 +1,150 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{CentralState, Mint, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_name_account, check_signer, normalize_ticker,
+        pay_fee,
+    },
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub ticker: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    fee_source: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            fee_source: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.ticker_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.ticker, accounts.ticker_name_account.key)?;
+
+    let mut ticker_record = Mint::from_name_account(accounts.ticker_name_account)?;
+    if ticker_record.expiry == 0 {
+        return Err(TokenRegistryError::TickerHasNoLease.into());
+    }
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let renewal_fee = central_state.registration_fee(&normalize_ticker(&params.ticker));
+    if renewal_fee != 0 {
+        pay_fee(
+            program_id,
+            &central_state,
+            accounts.system_program,
+            accounts.spl_token_program,
+            accounts.fee_payer,
+            accounts.fee_source,
+            accounts.treasury,
+            renewal_fee,
+        )?;
+    }
+
+    // Renewing once leases are disabled makes the ticker permanent
+    ticker_record.expiry = if central_state.lease_duration == 0 {
+        0
+    } else {
+        ticker_record.expiry.max(Clock::get()?.unix_timestamp) + central_state.lease_duration
+    };
+
+    let central_state_signer_seeds: &[&[u8]] =
+        &[&program_id.to_bytes(), &[central_state.signer_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.ticker_name_account,
+        accounts.central_state,
+        ticker_record.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{CentralState, Report, ReportReason, TokenData, TOKEN_TLD},
+    utils::{check_account_key, check_account_owner, check_name_account, check_signer},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: String,
+    pub reason: ReportReason,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    reporter: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    report_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            reporter: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            report_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.report_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.reporter)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let (report_key, nonce) = Report::find_key(program_id, &mint, accounts.reporter.key);
+    check_account_key(
+        accounts.report_account,
+        &report_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let report = Report::new(
+        mint.to_bytes(),
+        accounts.reporter.key.to_bytes(),
+        params.reason,
+        central_state.report_deposit,
+    );
+
+    let mint_bytes = mint.to_bytes();
+    let reporter_bytes = accounts.reporter.key.to_bytes();
+    let signer_seeds: &[&[u8]] = &[Report::SEED, &mint_bytes, &reporter_bytes, &[nonce]];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.reporter,
+        accounts.report_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        Report::LEN,
+    )?;
+
+    report.save(&mut accounts.report_account.data.borrow_mut());
+
+    if report.deposit != 0 {
+        Cpi::transfer_lamports(
+            accounts.system_program,
+            accounts.reporter,
+            accounts.report_account,
+            report.deposit,
+            &[],
+        )?;
+    }
+
+    // Counters are kept on the mint record
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    token_data.report_count = token_data.report_count.saturating_add(1);
+    token_data.open_report_count = token_data.open_report_count.saturating_add(1);
+
+    let central_state_signer_seeds: &[&[u8]] =
+        &[&program_id.to_bytes(), &[central_state.signer_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        token_data.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{BondedListing, CentralState, Tag},
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    /// `None` when the challenge is resolved by the arbiter of the central state
+    pub signer_index: Option<usize>,
+    /// Whether the listing is legitimate, the challenger is slashed if so
+    pub uphold_listing: bool,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    resolver: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+    registrant: &'a AccountInfo<'b>,
+    challenger: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            resolver: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+            registrant: next_account_info(accounts_iter)?,
+            challenger: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.listing_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.resolver)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    match params.signer_index {
+        #[cfg(not(feature = "test-bpf"))]
+        Some(signer_index) => check_registrar_signer(accounts.resolver.key, signer_index)?,
+        #[cfg(feature = "test-bpf")]
+        Some(_) => {}
+        None => {
+            let central_state =
+                CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+            if central_state.arbiter == [0; 32]
+                || accounts.resolver.key.to_bytes() != central_state.arbiter
+            {
+                msg!("The resolver is neither an admin nor the arbiter");
+                return Err(TokenRegistryError::NonWhiteListedSigner.into());
+            }
+        }
+    }
+
+    let mut listing = BondedListing::deserialize(&mut &accounts.listing_account.data.borrow()[..])?;
+    if listing.tag != Tag::BondedListing {
+        msg!("Invalid listing account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    if !listing.is_challenged() {
+        return Err(TokenRegistryError::NotChallenged.into());
+    }
+    check_account_key(
+        accounts.registrant,
+        &Pubkey::new(&listing.registrant),
+        TokenRegistryError::InvalidKey,
+    )?;
+    check_account_key(
+        accounts.challenger,
+        &Pubkey::new(&listing.challenger),
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    if params.uphold_listing {
+        // The counter-bond is slashed in favor of the registrant, the listing can then be finalized
+        **accounts.listing_account.lamports.borrow_mut() -= listing.counter_bond;
+        **accounts.registrant.lamports.borrow_mut() += listing.counter_bond;
+        listing.challenger = [0; 32];
+        listing.counter_bond = 0;
+        listing.save(&mut accounts.listing_account.data.borrow_mut());
+    } else {
+        // The bond is slashed in favor of the challenger, the registrant only gets the rent back
+        let payout = listing.bond + listing.counter_bond;
+        **accounts.listing_account.lamports.borrow_mut() -= payout;
+        **accounts.challenger.lamports.borrow_mut() += payout;
+        close_account(accounts.listing_account, accounts.registrant);
+    }
+
+    Ok(())
+}
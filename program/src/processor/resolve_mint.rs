@@ -0,0 +1,62 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{check_registry_record, TokenData, TokenSummary},
+    utils::{check_account_owner, check_name_account},
+};
+
+/// Read-only, returns the borsh serialized `TokenSummary` of the mint. The full metadata is
+/// read from the mint record
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    mint_name_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        _program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            mint_name_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+    check_registry_record(program_id, &accounts.mint_name_account.data.borrow())?;
+
+    let token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    set_return_data(&TokenSummary::from(&token_data).try_to_vec().unwrap());
+
+    Ok(())
+}
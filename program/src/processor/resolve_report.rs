@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{CentralState, Report, Tag, TokenData, TokenStatus, TOKEN_TLD},
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    /// Flags the reported token
+    pub flag_token: bool,
+    /// Refunds the deposit to the reporter, it goes to the treasury otherwise
+    pub refund_deposit: bool,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    admin: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    report_account: &'a AccountInfo<'b>,
+    reporter: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            admin: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            report_account: next_account_info(accounts_iter)?,
+            reporter: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+        check_account_key(
+            accounts.treasury,
+            &CentralState::find_treasury_key(program_id, &system_program::ID).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.report_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.admin)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.admin.key, params.signer_index)?;
+
+    let report = Report::deserialize(&mut &accounts.report_account.data.borrow()[..])?;
+    if report.tag != Tag::Report {
+        msg!("Invalid report account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    check_account_key(
+        accounts.reporter,
+        &Pubkey::new(&report.reporter),
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    if token_data.mint != report.mint {
+        msg!("The mint record does not match the report");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    token_data.open_report_count = token_data.open_report_count.saturating_sub(1);
+    // Denylisting takes precedence over flagging
+    if params.flag_token && token_data.status == TokenStatus::Active {
+        token_data.status = TokenStatus::Flagged;
+    }
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        token_data.try_to_vec().unwrap(),
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    // The rent of the report account always goes back to the reporter
+    if !params.refund_deposit {
+        **accounts.report_account.lamports.borrow_mut() -= report.deposit;
+        **accounts.treasury.lamports.borrow_mut() += report.deposit;
+    }
+    close_account(accounts.report_account, accounts.reporter);
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::{check_registry_record, Mint},
+    utils::{check_account_owner, check_name_account},
+};
+
+/// Read-only, returns the borsh serialized `Mint` of the ticker. Fails once the lease of the
+/// ticker has expired, even if it was not reclaimed yet
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub ticker: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    ticker_name_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        _program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            ticker_name_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.ticker_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.ticker, accounts.ticker_name_account.key)?;
+    check_registry_record(program_id, &accounts.ticker_name_account.data.borrow())?;
+
+    let mint = Mint::from_name_account(accounts.ticker_name_account)?;
+    if mint.expiry != 0 && Clock::get()?.unix_timestamp >= mint.expiry {
+        return Err(TokenRegistryError::LeaseExpired.into());
+    }
+    set_return_data(&mint.try_to_vec().unwrap());
+
+    Ok(())
+}
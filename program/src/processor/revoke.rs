@@ -0,0 +1,70 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::Attestation,
+    utils::{check_account_key, check_account_owner, check_signer, close_account},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: Pubkey,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    attester: &'a AccountInfo<'b>,
+    attestation_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            attester: next_account_info(accounts_iter)?,
+            attestation_account: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.attestation_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.attester)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    // The seeds tie the attestation to the signing attester
+    let (attestation_key, _) =
+        Attestation::find_key(program_id, &params.mint, accounts.attester.key);
+    check_account_key(
+        accounts.attestation_account,
+        &attestation_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    close_account(accounts.attestation_account, accounts.attester);
+
+    Ok(())
+}
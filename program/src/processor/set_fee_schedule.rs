@@ -0,0 +1,152 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{CentralState, FEE_TIERS},
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    /// The system program for fees paid in SOL
+    pub fee_mint: Pubkey,
+    /// Indexed by normalized ticker length
+    pub registration_fees: [u64; FEE_TIERS],
+    /// In lamports
+    pub application_deposit: u64,
+    /// In lamports, capped by the deposit
+    pub rejection_fee: u64,
+    /// In lamports
+    pub report_deposit: u64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    fee_mint: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            fee_mint: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    check_account_key(
+        accounts.fee_mint,
+        &params.fee_mint,
+        TokenRegistryError::InvalidKey,
+    )?;
+    let (treasury_key, treasury_nonce) =
+        CentralState::find_treasury_key(program_id, &params.fee_mint);
+    check_account_key(
+        accounts.treasury,
+        &treasury_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let mut central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+
+    // Fees paid in SOL are held directly by the treasury, tokens need a token account
+    if params.fee_mint != system_program::ID && accounts.treasury.data_len() == 0 {
+        check_account_owner(
+            accounts.fee_mint,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        let treasury_signer_seeds: &[&[u8]] = &[
+            &program_id.to_bytes(),
+            CentralState::TREASURY_SEED,
+            &params.fee_mint.to_bytes(),
+            &[treasury_nonce],
+        ];
+        Cpi::create_token_account(
+            accounts.spl_token_program,
+            accounts.system_program,
+            accounts.fee_payer,
+            accounts.treasury,
+            accounts.fee_mint,
+            accounts.central_state,
+            accounts.rent_sysvar_account,
+            treasury_signer_seeds,
+        )?;
+    }
+
+    central_state.fee_mint = params.fee_mint.to_bytes();
+    central_state.registration_fees = params.registration_fees;
+    central_state.application_deposit = params.application_deposit;
+    central_state.rejection_fee = params.rejection_fee;
+    central_state.report_deposit = params.report_deposit;
+    central_state.pack_into_slice(&mut accounts.central_state.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    state::CentralState,
+    utils::{check_account_owner, check_signer},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    /// In seconds, zero disables leases for new registrations
+    pub lease_duration: i64,
+    /// In seconds
+    pub grace_period: i64,
+    /// In slots
+    pub ticker_cooldown: u64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    admin: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            admin: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+        };
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.admin)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.admin.key, params.signer_index)?;
+
+    if params.lease_duration < 0 || params.grace_period < 0 {
+        msg!("Lease duration and grace period cannot be negative");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    central_state.lease_duration = params.lease_duration;
+    central_state.grace_period = params.grace_period;
+    central_state.ticker_cooldown = params.ticker_cooldown;
+    central_state.pack_into_slice(&mut accounts.central_state.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error::TokenRegistryError,
+    processor::register::{create_entry, EntryAccounts},
+    state::{Auction, CentralState, ReservedTicker, Tag, TokenData, VerificationTier, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_bonded_listing, check_denied_mint,
+        check_mint_authority, check_name_account, check_signer, close_account, get_extension_flags,
+        normalize_ticker,
+    },
+};
+
+/// Token details of the winning bidder, the ticker is the auctioned one. The winning bidder
+/// must be the mint authority and can settle until the settlement deadline, after which the
+/// bid can be refunded with `RefundAuction`
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub name: String,
+    pub mint: String,
+    pub decimals: u8,
+    pub website: String,
+    pub logo_uri: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    ticker_name_account: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    auction_account: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    mint_account: &'a AccountInfo<'b>,
+    legacy_ticker_name_account: &'a AccountInfo<'b>,
+    legacy_mint_name_account: &'a AccountInfo<'b>,
+    listing_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            ticker_name_account: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            auction_account: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            mint_account: next_account_info(accounts_iter)?,
+            legacy_ticker_name_account: next_account_info(accounts_iter)?,
+            legacy_mint_name_account: next_account_info(accounts_iter)?,
+            listing_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+        check_account_key(
+            accounts.treasury,
+            &CentralState::find_treasury_key(program_id, &system_program::ID).0,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.root_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.auction_account,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    let auction = Auction::deserialize(&mut &accounts.auction_account.data.borrow()[..])?;
+    if auction.tag != Tag::Auction {
+        msg!("Invalid auction account");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    if Clock::get()?.unix_timestamp < auction.end_timestamp {
+        return Err(TokenRegistryError::AuctionNotEnded.into());
+    }
+
+    // Without bids the ticker simply stays reserved
+    if auction.highest_bid == 0 {
+        close_account(accounts.auction_account, accounts.treasury);
+        return Ok(());
+    }
+
+    if accounts.fee_payer.key.to_bytes() != auction.highest_bidder {
+        msg!("Only the winning bidder can settle the auction");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+
+    let (reserved_ticker_key, _) =
+        ReservedTicker::find_key(program_id, &normalize_ticker(&auction.ticker));
+    check_account_key(
+        accounts.reserved_ticker_account,
+        &reserved_ticker_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    check_bonded_listing(program_id, &auction.ticker, accounts.listing_account)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+    // The winning bidder binds the ticker to a mint it controls
+    let (mint_state, token_program) =
+        check_mint_authority(&mint, accounts.mint_account, accounts.fee_payer)?;
+    if mint_state.decimals != params.decimals {
+        msg!("The decimals do not match the mint");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if accounts.ticker_name_account.data_len() != 0 {
+        msg!("Name account (ticker) is already initialized.");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    if accounts.mint_name_account.data_len() != 0 {
+        msg!("Name account (mint) is already initialized.");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    let hashed_ticker_name = check_name_account(
+        program_id,
+        &auction.ticker,
+        accounts.ticker_name_account.key,
+    )?;
+    let hashed_mint_name =
+        check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let mut token_data = TokenData::new(
+        VerificationTier::Unverified,
+        params.name,
+        auction.ticker,
+        mint.to_bytes(),
+        params.decimals,
+        params.website,
+        params.logo_uri,
+    );
+    token_data.token_program = token_program.to_bytes();
+    token_data.extension_flags = get_extension_flags(accounts.mint_account)?;
+
+    create_entry(
+        program_id,
+        &EntryAccounts {
+            name_service_program: accounts.name_service_program,
+            system_program: accounts.system_program,
+            fee_payer: accounts.fee_payer,
+            ticker_name_account: accounts.ticker_name_account,
+            mint_name_account: accounts.mint_name_account,
+            root_name_account: accounts.root_name_account,
+            central_state: accounts.central_state,
+            legacy_ticker_name_account: accounts.legacy_ticker_name_account,
+            legacy_mint_name_account: accounts.legacy_mint_name_account,
+        },
+        hashed_ticker_name,
+        hashed_mint_name,
+        &token_data,
+    )?;
+
+    // The escrowed bid and the reservation go to the treasury
+    close_account(accounts.auction_account, accounts.treasury);
+    if accounts.reserved_ticker_account.owner == program_id {
+        close_account(accounts.reserved_ticker_account, accounts.treasury);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::{self, Sysvar},
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{Auction, ReservedTicker},
+    utils::{
+        check_account_key, check_account_owner, check_normalized_ticker, check_signer,
+        get_hashed_name, normalize_ticker,
+    },
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    pub ticker: String,
+    pub end_timestamp: i64,
+    pub min_bid: u64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    fee_payer: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    auction_account: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            auction_account: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.auction_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.reserved_ticker_account,
+            program_id,
+            TokenRegistryError::TickerNotReserved,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.fee_payer)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.fee_payer.key, params.signer_index)?;
+
+    if params.end_timestamp <= Clock::get()?.unix_timestamp {
+        msg!("The auction must end in the future");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    check_normalized_ticker(&params.ticker)?;
+
+    // Only reserved tickers can be auctioned so that they cannot be registered meanwhile
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (reserved_ticker_key, _) = ReservedTicker::find_key(program_id, &normalized_ticker);
+    check_account_key(
+        accounts.reserved_ticker_account,
+        &reserved_ticker_key,
+        TokenRegistryError::TickerNotReserved,
+    )?;
+
+    let (auction_key, nonce) = Auction::find_key(program_id, &normalized_ticker);
+    check_account_key(
+        accounts.auction_account,
+        &auction_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let auction = Auction::new(params.ticker, params.end_timestamp, params.min_bid);
+    let hashed_ticker = get_hashed_name(&normalized_ticker);
+    let signer_seeds: &[&[u8]] = &[Auction::SEED, &hashed_ticker, &[nonce]];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.auction_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        auction.try_to_vec().unwrap().len(),
+    )?;
+
+    auction.save(&mut accounts.auction_account.data.borrow_mut());
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use borsh::BorshSerialize;
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    processor::register::entry_rent,
+    state::{Application, CentralState, Tag, TokenData, VerificationTier},
+    utils::{
+        check_account_key, check_account_owner, check_denied_mint, check_normalized_ticker,
+        check_reserved_ticker, check_signer, get_hashed_name, normalize_ticker, pay_fee,
+    },
+};
+
+/// The registration payload, `signer_index` must be `None`. The registration fee is charged at
+/// submission and is not refunded when the application is rejected
+pub use crate::processor::register::Params;
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    applicant: &'a AccountInfo<'b>,
+    rent_sysvar_account: &'a AccountInfo<'b>,
+    application_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    reserved_ticker_account: &'a AccountInfo<'b>,
+    denied_mint_account: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    fee_source: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            applicant: next_account_info(accounts_iter)?,
+            rent_sysvar_account: next_account_info(accounts_iter)?,
+            application_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            reserved_ticker_account: next_account_info(accounts_iter)?,
+            denied_mint_account: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            fee_source: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar_account,
+            &sysvar::rent::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.application_account,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.applicant)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    if params.signer_index.is_some() || params.update_authority.is_some() {
+        msg!("Applications cannot use an admin signer index or set an update authority");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Fail early on listings that could never be approved
+    check_normalized_ticker(&params.ticker)?;
+    check_reserved_ticker(program_id, &params.ticker, accounts.reserved_ticker_account)?;
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    check_denied_mint(program_id, &mint, accounts.denied_mint_account)?;
+
+    let normalized_ticker = normalize_ticker(&params.ticker);
+    let (application_key, nonce) =
+        Application::find_key(program_id, &normalized_ticker, accounts.applicant.key);
+    check_account_key(
+        accounts.application_account,
+        &application_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    let central_state = CentralState::unpack_from_slice(&accounts.central_state.data.borrow())?;
+    let registration_fee = central_state.registration_fee(&normalized_ticker);
+    if registration_fee != 0 {
+        pay_fee(
+            program_id,
+            &central_state,
+            accounts.system_program,
+            accounts.spl_token_program,
+            accounts.applicant,
+            accounts.fee_source,
+            accounts.treasury,
+            registration_fee,
+        )?;
+    }
+
+    let escrowed_rent = entry_rent(&TokenData::new(
+        VerificationTier::Community,
+        params.name.clone(),
+        params.ticker.clone(),
+        mint.to_bytes(),
+        params.decimals,
+        params.website.clone(),
+        params.logo_uri.clone(),
+    ))?;
+
+    let application = Application {
+        tag: Tag::Application,
+        applicant: accounts.applicant.key.to_bytes(),
+        deposit: central_state.application_deposit,
+        escrowed_rent,
+        ticker: params.ticker,
+        name: params.name,
+        mint: params.mint,
+        decimals: params.decimals,
+        website: params.website,
+        logo_uri: params.logo_uri,
+    };
+
+    let hashed_ticker = get_hashed_name(&normalized_ticker);
+    let signer_seeds: &[&[u8]] = &[
+        Application::SEED,
+        &hashed_ticker,
+        &accounts.applicant.key.to_bytes(),
+        &[nonce],
+    ];
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.applicant,
+        accounts.application_account,
+        accounts.rent_sysvar_account,
+        signer_seeds,
+        application.try_to_vec().unwrap().len(),
+    )?;
+
+    application.save(&mut accounts.application_account.data.borrow_mut());
+
+    // Escrow the deposit and the rent of the future name records
+    Cpi::transfer_lamports(
+        accounts.system_program,
+        accounts.applicant,
+        accounts.application_account,
+        application.deposit + application.escrowed_rent,
+        &[],
+    )?;
+
+    Ok(())
+}
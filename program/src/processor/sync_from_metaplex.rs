@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use spl_name_service::state::NameRecordHeader;
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{TokenData, TOKEN_TLD},
+    utils::{
+        check_account_key, check_account_owner, check_metaplex_metadata, check_metaplex_symbol,
+        check_name_account,
+    },
+};
+
+/// Permissionless, fills the empty name of the mint record from the Metaplex metadata. Values
+/// set by the registrant or the update authority are never overwritten. The Metaplex uri points
+/// to a JSON document, the logo uri is left untouched
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub mint: String,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    metaplex_metadata_account: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            metaplex_metadata_account: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let mint = Pubkey::from_str(&params.mint).map_err(|_| ProgramError::InvalidArgument)?;
+    let metadata = check_metaplex_metadata(&mint, accounts.metaplex_metadata_account)?;
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+    check_metaplex_symbol(&metadata, &token_data.ticker)?;
+    if token_data.name.is_empty() {
+        token_data.name = metadata.name;
+    }
+
+    let record_len = accounts.mint_name_account.data_len() - NameRecordHeader::LEN;
+    let mut data = token_data.try_to_vec().unwrap();
+    if data.len() > record_len {
+        return Err(TokenRegistryError::MetadataTooLong.into());
+    }
+    data.resize(record_len, 0);
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        data,
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use std::str::FromStr;
+
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use spl_name_service::state::NameRecordHeader;
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::{TokenData, TOKEN_TLD},
+    utils::{check_account_key, check_account_owner, check_name_account, check_signer},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+/// The ticker, mint and verification tier cannot be updated
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    /// `None` when signed by the update authority of the token
+    pub signer_index: Option<usize>,
+    pub mint: String,
+    pub name: Option<String>,
+    pub website: Option<String>,
+    pub logo_uri: Option<String>,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    name_service_program: &'a AccountInfo<'b>,
+    authority: &'a AccountInfo<'b>,
+    mint_name_account: &'a AccountInfo<'b>,
+    root_name_account: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            name_service_program: next_account_info(accounts_iter)?,
+            authority: next_account_info(accounts_iter)?,
+            mint_name_account: next_account_info(accounts_iter)?,
+            root_name_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.name_service_program,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.root_name_account,
+            &Pubkey::from_str(TOKEN_TLD).unwrap(),
+            TokenRegistryError::InvalidTld,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.mint_name_account,
+            &spl_name_service::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.authority)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+    check_name_account(program_id, &params.mint, accounts.mint_name_account.key)?;
+
+    let mut token_data = TokenData::from_name_account(accounts.mint_name_account)?;
+
+    match params.signer_index {
+        #[cfg(not(feature = "test-bpf"))]
+        Some(signer_index) => check_registrar_signer(accounts.authority.key, signer_index)?,
+        #[cfg(feature = "test-bpf")]
+        Some(_) => {}
+        None => {
+            if token_data.update_authority == [0; 32]
+                || accounts.authority.key.to_bytes() != token_data.update_authority
+            {
+                msg!("The signer is neither an admin nor the update authority");
+                return Err(TokenRegistryError::NonWhiteListedSigner.into());
+            }
+        }
+    }
+
+    if let Some(name) = params.name {
+        token_data.name = name;
+    }
+    if let Some(website) = params.website {
+        token_data.website = website;
+    }
+    if let Some(logo_uri) = params.logo_uri {
+        token_data.logo_uri = logo_uri;
+    }
+
+    // Records have a fixed size, the data is zero padded to overwrite the previous metadata
+    let record_len = accounts.mint_name_account.data_len() - NameRecordHeader::LEN;
+    let mut data = token_data.try_to_vec().unwrap();
+    if data.len() > record_len {
+        return Err(TokenRegistryError::MetadataTooLong.into());
+    }
+    data.resize(record_len, 0);
+
+    let central_state_nonce = accounts.central_state.data.borrow()[0];
+    let central_state_signer_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state_nonce]];
+
+    Cpi::update_name_account_data(
+        accounts.name_service_program,
+        accounts.mint_name_account,
+        accounts.central_state,
+        data,
+        accounts.root_name_account,
+        central_state_signer_seeds,
+    )?;
+
+    Ok(())
+}
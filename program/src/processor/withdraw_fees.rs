@@ -0,0 +1,126 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    cpi::Cpi,
+    error::TokenRegistryError,
+    state::CentralState,
+    utils::{check_account_key, check_account_owner, check_signer},
+};
+
+#[cfg(not(feature = "test-bpf"))]
+use crate::utils::check_registrar_signer;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Params {
+    pub signer_index: usize,
+    /// The system program for fees paid in SOL
+    pub fee_mint: Pubkey,
+    pub amount: u64,
+}
+
+struct Accounts<'a, 'b: 'a> {
+    system_program: &'a AccountInfo<'b>,
+    spl_token_program: &'a AccountInfo<'b>,
+    admin: &'a AccountInfo<'b>,
+    central_state: &'a AccountInfo<'b>,
+    treasury: &'a AccountInfo<'b>,
+    destination: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, 'b> {
+    fn parse_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'b>],
+    ) -> Result<Accounts<'a, 'b>, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            system_program: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            admin: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            treasury: next_account_info(accounts_iter)?,
+            destination: next_account_info(accounts_iter)?,
+        };
+
+        // Key checks
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Ownership checks
+        check_account_owner(
+            accounts.central_state,
+            program_id,
+            TokenRegistryError::InvalidKey,
+        )?;
+
+        // Signer checks
+        check_signer(accounts.admin)?;
+
+        Ok(accounts)
+    }
+}
+
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse_accounts(program_id, accounts)?;
+
+    #[cfg(not(feature = "test-bpf"))]
+    check_registrar_signer(accounts.admin.key, params.signer_index)?;
+
+    let (treasury_key, treasury_nonce) =
+        CentralState::find_treasury_key(program_id, &params.fee_mint);
+    check_account_key(
+        accounts.treasury,
+        &treasury_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+
+    if params.fee_mint == system_program::ID {
+        let treasury_signer_seeds: &[&[u8]] = &[
+            &program_id.to_bytes(),
+            CentralState::TREASURY_SEED,
+            &params.fee_mint.to_bytes(),
+            &[treasury_nonce],
+        ];
+        Cpi::transfer_lamports(
+            accounts.system_program,
+            accounts.treasury,
+            accounts.destination,
+            params.amount,
+            &[treasury_signer_seeds],
+        )?;
+    } else {
+        let central_state_nonce = accounts.central_state.data.borrow()[0];
+        let central_state_signer_seeds: &[&[u8]] =
+            &[&program_id.to_bytes(), &[central_state_nonce]];
+        Cpi::transfer_tokens(
+            accounts.spl_token_program,
+            accounts.treasury,
+            accounts.destination,
+            accounts.central_state,
+            params.amount,
+            &[central_state_signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,21 +1,112 @@
+use std::str::FromStr;
+
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    account_info::AccountInfo,
+    msg,
     program_error::ProgramError,
     program_pack::{Pack, Sealed},
+    pubkey::Pubkey,
+    system_program,
 };
+use spl_name_service::state::NameRecordHeader;
+
+use crate::{error::TokenRegistryError, utils::get_hashed_name};
 
 pub const ADMINS: [&str; 1] = ["9f9K1Jwoys9r7hQFwKB1aqrk7AT47D8UogM4s6npEKLa"];
 pub const TOKEN_TLD: &str = "6NSu2tci4apRKQtt257bAVcvqYjB3zV2H1dWo56vgpa6";
+pub const FEE_TIERS: usize = 5;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Tag {
+    Uninitialized,
+    ReservedTicker,
+    DeniedMint,
+    Auction,
+    Tombstone,
+    Application,
+    BondedListing,
+    Report,
+    Attester,
+    Attestation,
+    ClaimApproval,
+}
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct CentralState {
     pub signer_nonce: u8,
+    /// Mint in which registration fees are paid, the system program for SOL
+    pub fee_mint: [u8; 32],
+    /// Fees charged to non-admin registrations indexed by normalized ticker length,
+    /// the last entry applies to all longer tickers. A zero fee disables self-registration
+    pub registration_fees: [u64; FEE_TIERS],
+    /// Duration in seconds of ticker leases, tickers are registered forever when zero
+    pub lease_duration: i64,
+    /// Delay in seconds after the expiry of a lease before the ticker can be reclaimed
+    pub grace_period: i64,
+    /// Number of slots during which a reclaimed ticker can only be registered by an admin
+    pub ticker_cooldown: u64,
+    /// Lamports locked with every listing application
+    pub application_deposit: u64,
+    /// Lamports kept from the deposit of rejected applications
+    pub rejection_fee: u64,
+    /// Lamports locked with every bonded listing, zero disables bonded listings
+    pub registration_bond: u64,
+    /// Duration in seconds during which a bonded listing can be challenged
+    pub challenge_window: i64,
+    /// Key allowed to resolve challenges besides the admins, zeroed when unset
+    pub arbiter: [u8; 32],
+    /// Lamports locked with every user report
+    pub report_deposit: u64,
+}
+
+impl CentralState {
+    pub const TREASURY_SEED: &'static [u8] = b"treasury";
+
+    /// The central state is also the class of the name records issued by the registry
+    pub fn find_key(program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[&program_id.to_bytes()], program_id)
+    }
+
+    pub fn new(signer_nonce: u8) -> Self {
+        CentralState {
+            signer_nonce,
+            fee_mint: system_program::ID.to_bytes(),
+            registration_fees: [0; FEE_TIERS],
+            lease_duration: 0,
+            grace_period: 0,
+            ticker_cooldown: 0,
+            application_deposit: 0,
+            rejection_fee: 0,
+            registration_bond: 0,
+            challenge_window: 0,
+            arbiter: [0; 32],
+            report_deposit: 0,
+        }
+    }
+
+    /// The ticker must already be normalized
+    pub fn registration_fee(&self, ticker: &str) -> u64 {
+        let length = ticker.chars().count().clamp(1, FEE_TIERS);
+        self.registration_fees[length - 1]
+    }
+
+    pub fn find_treasury_key(program_id: &Pubkey, fee_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                &program_id.to_bytes(),
+                Self::TREASURY_SEED,
+                &fee_mint.to_bytes(),
+            ],
+            program_id,
+        )
+    }
 }
 
 impl Sealed for CentralState {}
 
 impl Pack for CentralState {
-    const LEN: usize = 1;
+    const LEN: usize = 169;
 
     fn pack_into_slice(&self, dst: &mut [u8]) {
         let mut pt = dst;
@@ -23,23 +114,66 @@ impl Pack for CentralState {
     }
 
     fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        if src.len() < Self::LEN {
+            msg!("The central state must be migrated with MigrateCentralState");
+            return Err(ProgramError::InvalidAccountData);
+        }
         let mut pt = src;
         let res = Self::deserialize(&mut pt)?;
         Ok(res)
     }
 }
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum TokenStatus {
+    Active,
+    Denylisted,
+    /// Flagged by a curator following user reports
+    Flagged,
+}
+
+/// How much the registry vouches for an entry
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum VerificationTier {
+    /// Self-registered by the mint authority
+    Unverified,
+    /// Listed through a community application or a bonded listing
+    Community,
+    /// Registered or vetted by an admin
+    Verified,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct TokenData {
+    pub status: TokenStatus,
+    pub tier: VerificationTier,
+    /// Key allowed to update the metadata of the token besides the admins, zeroed when unset
+    pub update_authority: [u8; 32],
+    /// Slot at which the project claimed the entry, zero when unclaimed
+    pub claim_slot: u64,
+    /// SPL Token or Token-2022
+    pub token_program: [u8; 32],
+    /// Risky Token-2022 extensions of the mint, see the `token_2022::FLAG_*` constants
+    pub extension_flags: u16,
     pub name: String,
     pub ticker: String,
     pub mint: [u8; 32],
     pub decimals: u8,
     pub website: String,
     pub logo_uri: String,
+    /// Number of reports ever filed against the token
+    pub report_count: u32,
+    /// Number of reports awaiting a curator decision
+    pub open_report_count: u32,
 }
 
 impl TokenData {
+    /// Offset of the verification tier in the record data, after the name record header
+    pub const TIER_OFFSET: usize = 1;
+    /// Extra space allocated to mint records so that their metadata can grow
+    pub const METADATA_PADDING: usize = 128;
+
     pub fn new(
+        tier: VerificationTier,
         name: String,
         ticker: String,
         mint: [u8; 32],
@@ -48,28 +182,580 @@ impl TokenData {
         logo_uri: String,
     ) -> Self {
         TokenData {
+            status: TokenStatus::Active,
+            tier,
+            update_authority: [0; 32],
+            claim_slot: 0,
+            token_program: spl_token::ID.to_bytes(),
+            extension_flags: 0,
             name,
             ticker,
             mint,
             decimals,
             website,
             logo_uri,
+            report_count: 0,
+            open_report_count: 0,
         }
     }
 
     pub fn save(&self, mut dst: &mut [u8]) {
         self.serialize(&mut dst).unwrap()
     }
+
+    /// Space of the mint record data, including the metadata padding
+    pub fn record_space(&self) -> usize {
+        self.try_to_vec().unwrap().len() + Self::METADATA_PADDING
+    }
+
+    /// The name account key must have been checked beforehand
+    pub fn from_name_account(name_account: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = name_account.data.borrow();
+        let mut pt = data
+            .get(NameRecordHeader::LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Self::deserialize(&mut pt).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Fixed-size view of an entry, returned by `ResolveMint` so that it always fits in the return
+/// data whatever the length of the metadata
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TokenSummary {
+    pub mint: [u8; 32],
+    pub decimals: u8,
+    pub status: TokenStatus,
+    pub tier: VerificationTier,
+    pub extension_flags: u16,
+}
+
+impl From<&TokenData> for TokenSummary {
+    fn from(token_data: &TokenData) -> Self {
+        TokenSummary {
+            mint: token_data.mint,
+            decimals: token_data.decimals,
+            status: token_data.status,
+            tier: token_data.tier,
+            extension_flags: token_data.extension_flags,
+        }
+    }
+}
+
+/// Layout of the mint records issued before the registry tracked the status of its entries.
+/// These records live at the legacy class-less addresses until they are migrated
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LegacyTokenData {
+    pub name: String,
+    pub ticker: String,
+    pub mint: [u8; 32],
+    pub decimals: u8,
+    pub website: String,
+    pub logo_uri: String,
+}
+
+impl From<LegacyTokenData> for TokenData {
+    /// Legacy entries could only be registered by the admins
+    fn from(legacy: LegacyTokenData) -> Self {
+        TokenData::new(
+            VerificationTier::Verified,
+            legacy.name,
+            legacy.ticker,
+            legacy.mint,
+            legacy.decimals,
+            legacy.website,
+            legacy.logo_uri,
+        )
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct Mint {
     pub mint: [u8; 32],
+    /// Unix timestamp at which the ticker lease expires, zero when the ticker has no lease
+    pub expiry: i64,
 }
 
 impl Mint {
+    pub fn new(mint: [u8; 32], expiry: i64) -> Self {
+        Mint { mint, expiry }
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+
+    /// The name account key must have been checked beforehand
+    pub fn from_name_account(name_account: &AccountInfo) -> Result<Self, ProgramError> {
+        let data = name_account.data.borrow();
+        let mut pt = data
+            .get(NameRecordHeader::LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Self::deserialize(&mut pt).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Ticker that can only be registered once an admin releases it
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ReservedTicker {
+    pub tag: Tag,
+    pub ticker: String,
+}
+
+impl ReservedTicker {
+    pub const SEED: &'static [u8] = b"reserved_ticker";
+
+    pub fn new(ticker: String) -> Self {
+        ReservedTicker {
+            tag: Tag::ReservedTicker,
+            ticker,
+        }
+    }
+
+    /// The ticker must already be normalized
+    pub fn find_key(program_id: &Pubkey, ticker: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &get_hashed_name(ticker)], program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// Mint that can never be registered
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DeniedMint {
+    pub tag: Tag,
+    pub mint: [u8; 32],
+}
+
+impl DeniedMint {
+    pub const SEED: &'static [u8] = b"denied_mint";
+    pub const LEN: usize = 33;
+
     pub fn new(mint: [u8; 32]) -> Self {
-        Mint { mint }
+        DeniedMint {
+            tag: Tag::DeniedMint,
+            mint,
+        }
+    }
+
+    pub fn find_key(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &mint.to_bytes()], program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// Ascending auction for a reserved ticker, the account escrows the highest bid
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Auction {
+    pub tag: Tag,
+    pub end_timestamp: i64,
+    pub min_bid: u64,
+    pub highest_bid: u64,
+    pub highest_bidder: [u8; 32],
+    pub ticker: String,
+}
+
+impl Auction {
+    pub const SEED: &'static [u8] = b"auction";
+    /// Seconds after the end of the auction during which only the winning bidder can settle it
+    pub const SETTLEMENT_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+    pub fn new(ticker: String, end_timestamp: i64, min_bid: u64) -> Self {
+        Auction {
+            tag: Tag::Auction,
+            end_timestamp,
+            min_bid,
+            highest_bid: 0,
+            highest_bidder: [0; 32],
+            ticker,
+        }
+    }
+
+    /// The ticker must already be normalized
+    pub fn find_key(program_id: &Pubkey, ticker: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &get_hashed_name(ticker)], program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+
+    /// The winning bid can be refunded from this timestamp when the auction is not settled
+    pub fn settlement_deadline(&self) -> i64 {
+        self.end_timestamp.saturating_add(Self::SETTLEMENT_PERIOD)
+    }
+}
+
+/// Record of a deleted ticker
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Tombstone {
+    pub tag: Tag,
+    pub deletion_slot: u64,
+    pub previous_mint: [u8; 32],
+}
+
+impl Tombstone {
+    pub const SEED: &'static [u8] = b"tombstone";
+    pub const LEN: usize = 41;
+
+    pub fn new(deletion_slot: u64, previous_mint: [u8; 32]) -> Self {
+        Tombstone {
+            tag: Tag::Tombstone,
+            deletion_slot,
+            previous_mint,
+        }
+    }
+
+    /// The ticker must already be normalized
+    pub fn find_key(program_id: &Pubkey, ticker: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &get_hashed_name(ticker)], program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// Listing application awaiting an admin decision. Open applications can be enumerated
+/// by filtering the program accounts on the tag
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Application {
+    pub tag: Tag,
+    pub applicant: [u8; 32],
+    pub deposit: u64,
+    /// Lamports escrowed to pay for the name records once approved
+    pub escrowed_rent: u64,
+    pub ticker: String,
+    pub name: String,
+    pub mint: String,
+    pub decimals: u8,
+    pub website: String,
+    pub logo_uri: String,
+}
+
+impl Application {
+    pub const SEED: &'static [u8] = b"application";
+
+    /// The ticker must already be normalized
+    pub fn find_key(program_id: &Pubkey, ticker: &str, applicant: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, &get_hashed_name(ticker), &applicant.to_bytes()],
+            program_id,
+        )
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// Permissionless listing backed by a bond, it can be challenged until `challenge_end`.
+/// The account escrows the bond, the counter-bond of the challenger and the rent of the
+/// name records
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct BondedListing {
+    pub tag: Tag,
+    pub registrant: [u8; 32],
+    pub bond: u64,
+    pub escrowed_rent: u64,
+    pub challenge_end: i64,
+    /// Zeroed when the listing is not disputed
+    pub challenger: [u8; 32],
+    pub counter_bond: u64,
+    pub ticker: String,
+    pub name: String,
+    pub mint: String,
+    pub decimals: u8,
+    pub website: String,
+    pub logo_uri: String,
+}
+
+impl BondedListing {
+    pub const SEED: &'static [u8] = b"bonded_listing";
+
+    /// The ticker must already be normalized
+    pub fn find_key(program_id: &Pubkey, ticker: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &get_hashed_name(ticker)], program_id)
+    }
+
+    pub fn is_challenged(&self) -> bool {
+        self.challenger != [0; 32]
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ReportReason {
+    Scam,
+    Impersonation,
+    WrongMint,
+    WrongMetadata,
+    Other,
+}
+
+/// User report against a registered token, the account escrows the deposit of the reporter
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Report {
+    pub tag: Tag,
+    pub mint: [u8; 32],
+    pub reporter: [u8; 32],
+    pub reason: ReportReason,
+    pub deposit: u64,
+}
+
+impl Report {
+    pub const SEED: &'static [u8] = b"report";
+    pub const LEN: usize = 74;
+
+    pub fn new(mint: [u8; 32], reporter: [u8; 32], reason: ReportReason, deposit: u64) -> Self {
+        Report {
+            tag: Tag::Report,
+            mint,
+            reporter,
+            reason,
+            deposit,
+        }
+    }
+
+    pub fn find_key(program_id: &Pubkey, mint: &Pubkey, reporter: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, &mint.to_bytes(), &reporter.to_bytes()],
+            program_id,
+        )
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// External verifier allowed to attest to registered tokens
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Attester {
+    pub tag: Tag,
+    pub attester: [u8; 32],
+    pub name: String,
+}
+
+impl Attester {
+    pub const SEED: &'static [u8] = b"attester";
+
+    pub fn new(attester: [u8; 32], name: String) -> Self {
+        Attester {
+            tag: Tag::Attester,
+            attester,
+            name,
+        }
+    }
+
+    pub fn find_key(program_id: &Pubkey, attester: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &attester.to_bytes()], program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AttestationKind {
+    Audit,
+    ExchangeListing,
+    DataProvider,
+    Other,
+}
+
+/// Statement of an attester about a registered mint
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Attestation {
+    pub tag: Tag,
+    pub mint: [u8; 32],
+    pub attester: [u8; 32],
+    pub kind: AttestationKind,
+    pub issued_at: i64,
+    /// Unix timestamp after which the attestation no longer holds, zero when it does not expire
+    pub expiry: i64,
+}
+
+impl Attestation {
+    pub const SEED: &'static [u8] = b"attestation";
+    pub const LEN: usize = 82;
+    /// Offset of the mint, to be used in `memcmp` filters
+    pub const MINT_OFFSET: usize = 1;
+
+    pub fn find_key(program_id: &Pubkey, mint: &Pubkey, attester: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::SEED, &mint.to_bytes(), &attester.to_bytes()],
+            program_id,
+        )
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expiry != 0 && now >= self.expiry
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) {
+        self.serialize(&mut dst).unwrap()
+    }
+}
+
+/// Lists the valid attestations for a mint out of the data of the program accounts. An
+/// attestation is valid when it has not expired and its attester is still registered
+pub fn valid_attestations<'a>(
+    mint: &Pubkey,
+    program_accounts: impl IntoIterator<Item = &'a [u8]>,
+    now: i64,
+) -> Vec<Attestation> {
+    let mut attesters = Vec::new();
+    let mut attestations = Vec::new();
+    for data in program_accounts {
+        match data.first() {
+            Some(tag) if *tag == Tag::Attester as u8 => {
+                if let Ok(attester) = Attester::deserialize(&mut &data[..]) {
+                    attesters.push(attester.attester);
+                }
+            }
+            Some(tag) if *tag == Tag::Attestation as u8 => {
+                if let Ok(attestation) = Attestation::deserialize(&mut &data[..]) {
+                    if attestation.mint == mint.to_bytes() && !attestation.is_expired(now) {
+                        attestations.push(attestation);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    attestations.retain(|a| attesters.contains(&a.attester));
+    attestations
+}
+
+/// Checks that a name record was issued by the registry before trusting its content:
+/// its parent must be the token TLD, and its owner and class the central state.
+/// Legacy records are rejected, they must be migrated with `MigrateEntry` first
+pub fn check_registry_record(program_id: &Pubkey, record_data: &[u8]) -> Result<(), ProgramError> {
+    let header = registry_record_header(program_id, record_data)?;
+    if header.class == Pubkey::default() {
+        return Err(TokenRegistryError::InvalidRecordHeader.into());
+    }
+    Ok(())
+}
+
+/// Also accepts the legacy class-less records
+fn registry_record_header(
+    program_id: &Pubkey,
+    record_data: &[u8],
+) -> Result<NameRecordHeader, TokenRegistryError> {
+    let header = record_data
+        .get(..NameRecordHeader::LEN)
+        .and_then(|data| NameRecordHeader::unpack_from_slice(data).ok())
+        .ok_or(TokenRegistryError::InvalidRecordHeader)?;
+    let (central_state, _) = CentralState::find_key(program_id);
+    if header.parent_name != Pubkey::from_str(TOKEN_TLD).unwrap()
+        || header.owner != central_state
+        || (header.class != central_state && header.class != Pubkey::default())
+    {
+        return Err(TokenRegistryError::InvalidRecordHeader);
+    }
+    Ok(header)
+}
+
+/// Mint record of the registry, the name account of a mint
+#[derive(Debug)]
+pub struct TokenRecord {
+    pub header: NameRecordHeader,
+    pub data: TokenData,
+}
+
+impl TokenRecord {
+    /// Parses the data of a mint name account, the zero padding after the token data is ignored.
+    /// Legacy records are converted to the current layout
+    ///
+    /// The header does not tell mint and ticker records apart, the caller must check that the
+    /// key of the account is the one derived with `derive_mint_account`
+    pub fn from_account_data(
+        program_id: &Pubkey,
+        account_data: &[u8],
+    ) -> Result<Self, TokenRegistryError> {
+        let header = registry_record_header(program_id, account_data)?;
+        let mut pt = &account_data[NameRecordHeader::LEN..];
+        let data = if header.class == Pubkey::default() {
+            LegacyTokenData::deserialize(&mut pt).map(TokenData::from)
+        } else {
+            TokenData::deserialize(&mut pt)
+        }
+        .map_err(|_| TokenRegistryError::InvalidRecordData)?;
+        Ok(TokenRecord { header, data })
+    }
+
+    /// Issued before the central state became the class of the records, see `MigrateEntry`
+    pub fn is_legacy(&self) -> bool {
+        self.header.class == Pubkey::default()
+    }
+}
+
+/// Ticker record of the registry, the name account of a ticker
+#[derive(Debug)]
+pub struct TickerRecord {
+    pub header: NameRecordHeader,
+    pub data: Mint,
+}
+
+impl TickerRecord {
+    /// Parses the data of a ticker name account, the bytes after the mint are ignored.
+    /// Legacy records only hold the mint, their ticker has no lease
+    ///
+    /// A mint record also parses as a ticker record, the caller must check that the key of the
+    /// account is the one derived with `derive_ticker_account`
+    pub fn from_account_data(
+        program_id: &Pubkey,
+        account_data: &[u8],
+    ) -> Result<Self, TokenRegistryError> {
+        let header = registry_record_header(program_id, account_data)?;
+        let mut pt = &account_data[NameRecordHeader::LEN..];
+        let data = if header.class == Pubkey::default() {
+            <[u8; 32]>::deserialize(&mut pt).map(|mint| Mint::new(mint, 0))
+        } else {
+            Mint::deserialize(&mut pt)
+        }
+        .map_err(|_| TokenRegistryError::InvalidRecordData)?;
+        Ok(TickerRecord { header, data })
+    }
+
+    /// Issued before the central state became the class of the records, see `MigrateEntry`
+    pub fn is_legacy(&self) -> bool {
+        self.header.class == Pubkey::default()
+    }
+}
+
+/// Key allowed by an admin to claim the entry of a mint
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ClaimApproval {
+    pub tag: Tag,
+    pub mint: [u8; 32],
+    pub claimant: [u8; 32],
+}
+
+impl ClaimApproval {
+    pub const SEED: &'static [u8] = b"claim_approval";
+    pub const LEN: usize = 65;
+
+    pub fn new(mint: [u8; 32], claimant: [u8; 32]) -> Self {
+        ClaimApproval {
+            tag: Tag::ClaimApproval,
+            mint,
+            claimant,
+        }
+    }
+
+    pub fn find_key(program_id: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::SEED, &mint.to_bytes()], program_id)
     }
 
     pub fn save(&self, mut dst: &mut [u8]) {
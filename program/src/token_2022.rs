@@ -0,0 +1,72 @@
+//! Minimal Token-2022 definitions, the base mint layout is shared with SPL Token
+
+use solana_program::{program_error::ProgramError, program_pack::Pack};
+use spl_token::state::Mint;
+
+solana_program::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PazPNdxWbs8B6Sxqx");
+
+/// Mints with extensions are padded to the size of a token account, followed by the account type
+pub const ACCOUNT_TYPE_OFFSET: usize = spl_token::state::Account::LEN;
+pub const MINT_ACCOUNT_TYPE: u8 = 1;
+
+/// Parses the base state of a Token-2022 mint, with or without extensions
+pub fn unpack_mint_base(data: &[u8]) -> Result<Mint, ProgramError> {
+    if data.len() != Mint::LEN && data.get(ACCOUNT_TYPE_OFFSET) != Some(&MINT_ACCOUNT_TYPE) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let mint = Mint::unpack_from_slice(&data[..Mint::LEN])?;
+    if !mint.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    Ok(mint)
+}
+
+/// Risk flags derived from the extensions of a Token-2022 mint
+pub const FLAG_PERMANENT_DELEGATE: u16 = 1 << 0;
+pub const FLAG_TRANSFER_HOOK: u16 = 1 << 1;
+pub const FLAG_TRANSFER_FEE: u16 = 1 << 2;
+pub const FLAG_DEFAULT_FROZEN: u16 = 1 << 3;
+pub const FLAG_CONFIDENTIAL_TRANSFERS: u16 = 1 << 4;
+pub const FLAG_NON_TRANSFERABLE: u16 = 1 << 5;
+
+/// Token-2022 extension types, as laid out in the TLV entries of the mint
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_CONFIDENTIAL_TRANSFER_MINT: u16 = 4;
+const EXTENSION_DEFAULT_ACCOUNT_STATE: u16 = 6;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_TRANSFER_HOOK: u16 = 14;
+
+/// `AccountState::Frozen` in the default account state extension
+const ACCOUNT_STATE_FROZEN: u8 = 2;
+
+/// Walks the extensions of a Token-2022 mint and returns the matching risk flags
+pub fn extension_flags(data: &[u8]) -> Result<u16, ProgramError> {
+    unpack_mint_base(data)?;
+    let mut flags = 0;
+    let mut offset = ACCOUNT_TYPE_OFFSET + 1;
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        // The remaining space of the account is zeroed
+        if extension_type == 0 {
+            break;
+        }
+        let value = data
+            .get(offset + 4..offset + 4 + length)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        flags |= match extension_type {
+            EXTENSION_TRANSFER_FEE_CONFIG => FLAG_TRANSFER_FEE,
+            EXTENSION_CONFIDENTIAL_TRANSFER_MINT => FLAG_CONFIDENTIAL_TRANSFERS,
+            EXTENSION_DEFAULT_ACCOUNT_STATE if value.first() == Some(&ACCOUNT_STATE_FROZEN) => {
+                FLAG_DEFAULT_FROZEN
+            }
+            EXTENSION_NON_TRANSFERABLE => FLAG_NON_TRANSFERABLE,
+            EXTENSION_PERMANENT_DELEGATE => FLAG_PERMANENT_DELEGATE,
+            EXTENSION_TRANSFER_HOOK => FLAG_TRANSFER_HOOK,
+            _ => 0,
+        };
+        offset += 4 + length;
+    }
+    Ok(flags)
+}
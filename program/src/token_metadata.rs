@@ -0,0 +1,36 @@
+//! Minimal `spl-token-metadata-interface` definitions, the registry only answers `Emit`
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::TokenData;
+
+/// First 8 bytes of `sha256("spl_token_metadata_interface:emitter")`
+pub const EMIT_DISCRIMINATOR: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+/// Layout of the interface's `TokenMetadata`, a zeroed update authority stands for `None`
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+pub struct TokenMetadata {
+    pub update_authority: [u8; 32],
+    pub mint: [u8; 32],
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+impl From<TokenData> for TokenMetadata {
+    fn from(token_data: TokenData) -> Self {
+        let mut additional_metadata = vec![];
+        if !token_data.website.is_empty() {
+            additional_metadata.push(("website".to_string(), token_data.website));
+        }
+        TokenMetadata {
+            update_authority: token_data.update_authority,
+            mint: token_data.mint,
+            name: token_data.name,
+            symbol: token_data.ticker,
+            uri: token_data.logo_uri,
+            additional_metadata,
+        }
+    }
+}
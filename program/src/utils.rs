@@ -1,15 +1,19 @@
-use std::str::FromStr;
-
+use borsh::BorshDeserialize;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, hash::hashv, msg,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, hash::hashv, msg,
+    program_error::ProgramError, program_option::COption, program_pack::Pack, pubkey::Pubkey,
+    system_program, sysvar::Sysvar,
 };
 
 use crate::{
+    cpi::Cpi,
+    derivation::{derive_legacy_name_account, derive_name_account},
     error::TokenRegistryError,
-    state::{ADMINS, TOKEN_TLD},
+    metaplex,
+    state::{Auction, BondedListing, CentralState, DeniedMint, ReservedTicker, Tombstone, ADMINS},
+    token_2022,
 };
-use spl_name_service::state::{get_seeds_and_key, HASH_PREFIX};
+use spl_name_service::state::HASH_PREFIX;
 
 // Safety verification functions
 pub fn check_account_key(
@@ -48,22 +52,41 @@ pub fn check_registrar_signer(key: &Pubkey, index: usize) -> ProgramResult {
     Ok(())
 }
 
-pub fn check_name_account(name: &str, unsafe_name_key: &Pubkey) -> Result<Vec<u8>, ProgramError> {
-    let hashed_name = hashv(&[(HASH_PREFIX.to_owned() + name).as_bytes()])
+pub fn get_hashed_name(name: &str) -> Vec<u8> {
+    hashv(&[(HASH_PREFIX.to_owned() + name).as_bytes()])
         .0
-        .to_vec();
+        .to_vec()
+}
+
+/// Tickers are compared case-insensitively and without surrounding whitespace
+pub fn normalize_ticker(ticker: &str) -> String {
+    ticker.trim().to_uppercase()
+}
+
+/// Reservations and tombstones are keyed by the normalized ticker, so entries must be
+/// registered under it for both to apply to the name records
+pub fn check_normalized_ticker(ticker: &str) -> ProgramResult {
+    if ticker.is_empty() || ticker != normalize_ticker(ticker) {
+        msg!("The ticker must be non-empty, uppercase and without surrounding whitespace");
+        return Err(TokenRegistryError::TickerNotNormalized.into());
+    }
+    Ok(())
+}
+
+/// Name records of the registry are derived with the registry class
+pub fn check_name_account(
+    program_id: &Pubkey,
+    name: &str,
+    unsafe_name_key: &Pubkey,
+) -> Result<Vec<u8>, ProgramError> {
+    let hashed_name = get_hashed_name(name);
 
     if hashed_name.len() != 32 {
         msg!("Invalid seed length");
         return Err(ProgramError::InvalidArgument);
     }
 
-    let (name_account_key, _) = get_seeds_and_key(
-        &spl_name_service::ID,
-        hashed_name.clone(),
-        None,
-        Some(&Pubkey::from_str(TOKEN_TLD).unwrap()),
-    );
+    let name_account_key = derive_name_account(program_id, hashed_name.clone());
 
     if name_account_key != *unsafe_name_key {
         msg!("Provided wrong name account");
@@ -72,3 +95,241 @@ pub fn check_name_account(name: &str, unsafe_name_key: &Pubkey) -> Result<Vec<u8
 
     Ok(hashed_name)
 }
+
+/// Names registered at their legacy class-less address cannot be registered again until the
+/// legacy records are migrated
+pub fn check_legacy_name_account(
+    name: &str,
+    unsafe_legacy_name_account: &AccountInfo,
+) -> ProgramResult {
+    check_account_key(
+        unsafe_legacy_name_account,
+        &derive_legacy_name_account(get_hashed_name(name)),
+        TokenRegistryError::InvalidNameProvided,
+    )?;
+    if unsafe_legacy_name_account.data_len() != 0 {
+        msg!("{} is registered at its legacy address", name);
+        return Err(TokenRegistryError::AlreadyRegistered.into());
+    }
+    Ok(())
+}
+
+pub fn check_reserved_ticker(
+    program_id: &Pubkey,
+    ticker: &str,
+    unsafe_reserved_ticker_account: &AccountInfo,
+) -> ProgramResult {
+    let (reserved_ticker_key, _) = ReservedTicker::find_key(program_id, &normalize_ticker(ticker));
+    check_account_key(
+        unsafe_reserved_ticker_account,
+        &reserved_ticker_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if unsafe_reserved_ticker_account.data_len() != 0 {
+        msg!("Ticker {} is reserved", ticker);
+        return Err(TokenRegistryError::TickerReserved.into());
+    }
+    Ok(())
+}
+
+/// Tickers with a pending bonded listing are left to the listing until it is finalized or closed
+pub fn check_bonded_listing(
+    program_id: &Pubkey,
+    ticker: &str,
+    unsafe_listing_account: &AccountInfo,
+) -> ProgramResult {
+    let (listing_key, _) = BondedListing::find_key(program_id, &normalize_ticker(ticker));
+    check_account_key(
+        unsafe_listing_account,
+        &listing_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if unsafe_listing_account.data_len() != 0 {
+        msg!("Ticker {} has a pending bonded listing", ticker);
+        return Err(TokenRegistryError::ListingPending.into());
+    }
+    Ok(())
+}
+
+/// Tickers under auction are left to the auction until it is settled or refunded
+pub fn check_auction(
+    program_id: &Pubkey,
+    ticker: &str,
+    unsafe_auction_account: &AccountInfo,
+) -> ProgramResult {
+    let (auction_key, _) = Auction::find_key(program_id, &normalize_ticker(ticker));
+    check_account_key(
+        unsafe_auction_account,
+        &auction_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if unsafe_auction_account.data_len() != 0 {
+        msg!("Ticker {} is being auctioned", ticker);
+        return Err(TokenRegistryError::AuctionPending.into());
+    }
+    Ok(())
+}
+
+/// Checks that the account is a mint of SPL Token or Token-2022 and returns its base state
+/// along with the token program that owns it
+pub fn check_mint_account(
+    mint: &Pubkey,
+    unsafe_mint_account: &AccountInfo,
+) -> Result<(spl_token::state::Mint, Pubkey), ProgramError> {
+    check_account_key(unsafe_mint_account, mint, TokenRegistryError::InvalidKey)?;
+    let token_program = *unsafe_mint_account.owner;
+    let data = unsafe_mint_account.data.borrow();
+    let mint_state = if token_program == spl_token::ID {
+        spl_token::state::Mint::unpack(&data)?
+    } else if token_program == token_2022::ID {
+        token_2022::unpack_mint_base(&data)?
+    } else {
+        msg!("The mint is not owned by a token program");
+        return Err(TokenRegistryError::InvalidKey.into());
+    };
+    Ok((mint_state, token_program))
+}
+
+/// Checks the derivation of the Metaplex metadata account of the mint and parses it
+pub fn check_metaplex_metadata(
+    mint: &Pubkey,
+    unsafe_metadata_account: &AccountInfo,
+) -> Result<metaplex::Metadata, ProgramError> {
+    let (metadata_key, _) = metaplex::Metadata::find_key(mint);
+    check_account_key(
+        unsafe_metadata_account,
+        &metadata_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    check_account_owner(
+        unsafe_metadata_account,
+        &metaplex::ID,
+        TokenRegistryError::InvalidKey,
+    )?;
+    let metadata = metaplex::Metadata::unpack(&unsafe_metadata_account.data.borrow())?;
+    if metadata.mint != mint.to_bytes() {
+        msg!("The Metaplex metadata belongs to another mint");
+        return Err(TokenRegistryError::InvalidKey.into());
+    }
+    Ok(metadata)
+}
+
+/// Rejects tickers that diverge from the symbol of the Metaplex metadata
+pub fn check_metaplex_symbol(metadata: &metaplex::Metadata, ticker: &str) -> ProgramResult {
+    if normalize_ticker(&metadata.symbol) != normalize_ticker(ticker) {
+        msg!(
+            "Ticker {} does not match the Metaplex symbol {}",
+            ticker,
+            metadata.symbol
+        );
+        return Err(TokenRegistryError::TickerMismatch.into());
+    }
+    Ok(())
+}
+
+/// Risk flags of a mint that was checked beforehand, SPL Token mints have no extensions
+pub fn get_extension_flags(mint_account: &AccountInfo) -> Result<u16, ProgramError> {
+    if *mint_account.owner != token_2022::ID {
+        return Ok(0);
+    }
+    token_2022::extension_flags(&mint_account.data.borrow())
+}
+
+/// Checks that the signer is the mint authority of the mint
+pub fn check_mint_authority(
+    mint: &Pubkey,
+    unsafe_mint_account: &AccountInfo,
+    authority: &AccountInfo,
+) -> Result<(spl_token::state::Mint, Pubkey), ProgramError> {
+    let (mint_state, token_program) = check_mint_account(mint, unsafe_mint_account)?;
+    if !authority.is_signer || mint_state.mint_authority != COption::Some(*authority.key) {
+        return Err(TokenRegistryError::NotMintAuthority.into());
+    }
+    Ok((mint_state, token_program))
+}
+
+pub fn check_denied_mint(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    unsafe_denied_mint_account: &AccountInfo,
+) -> ProgramResult {
+    let (denied_mint_key, _) = DeniedMint::find_key(program_id, mint);
+    check_account_key(
+        unsafe_denied_mint_account,
+        &denied_mint_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if unsafe_denied_mint_account.data_len() != 0 {
+        msg!("Mint {} is denylisted", mint);
+        return Err(TokenRegistryError::MintDenylisted.into());
+    }
+    Ok(())
+}
+
+/// Rejects tickers that were deleted less than `cooldown` slots ago
+pub fn check_tombstone(
+    program_id: &Pubkey,
+    ticker: &str,
+    unsafe_tombstone_account: &AccountInfo,
+    cooldown: u64,
+) -> ProgramResult {
+    let (tombstone_key, _) = Tombstone::find_key(program_id, &normalize_ticker(ticker));
+    check_account_key(
+        unsafe_tombstone_account,
+        &tombstone_key,
+        TokenRegistryError::InvalidKey,
+    )?;
+    if unsafe_tombstone_account.data_len() == 0 {
+        return Ok(());
+    }
+
+    let tombstone = Tombstone::deserialize(&mut &unsafe_tombstone_account.data.borrow()[..])?;
+    let available_from = tombstone.deletion_slot.saturating_add(cooldown);
+    if Clock::get()?.slot < available_from {
+        msg!(
+            "Ticker {} can be registered from slot {}",
+            ticker,
+            available_from
+        );
+        return Err(TokenRegistryError::TickerInCooldown.into());
+    }
+    Ok(())
+}
+
+/// Transfers all the lamports of the account to the target and wipes its data
+pub fn close_account(account: &AccountInfo, target: &AccountInfo) {
+    let lamports = account.lamports();
+    **target.lamports.borrow_mut() += lamports;
+    **account.lamports.borrow_mut() = 0;
+    account.data.borrow_mut().fill(0);
+}
+
+/// Pays a fee to the treasury in the mint of the fee schedule
+#[allow(clippy::too_many_arguments)]
+pub fn pay_fee<'a>(
+    program_id: &Pubkey,
+    central_state: &CentralState,
+    system_program: &AccountInfo<'a>,
+    spl_token_program: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    fee_source: &AccountInfo<'a>,
+    treasury: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let fee_mint = Pubkey::new(&central_state.fee_mint);
+    let (treasury_key, _) = CentralState::find_treasury_key(program_id, &fee_mint);
+    check_account_key(treasury, &treasury_key, TokenRegistryError::InvalidKey)?;
+
+    if fee_mint == system_program::ID {
+        Cpi::transfer_lamports(system_program, fee_payer, treasury, amount, &[])
+    } else {
+        Cpi::transfer_tokens(
+            spl_token_program,
+            fee_source,
+            treasury,
+            fee_payer,
+            amount,
+            &[],
+        )
+    }
+}
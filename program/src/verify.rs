@@ -0,0 +1,212 @@
+//! Verification helpers for programs that read the registry without a CPI
+//!
+//! The name accounts are checked the same way the registry checks them: the key is re-derived
+//! with the registry class, then the owner, parent and class of the record are verified. Legacy
+//! records are rejected until they are migrated with `MigrateEntry`.
+
+use std::{cell::Ref, convert::TryInto};
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+use spl_name_service::state::NameRecordHeader;
+
+use crate::{
+    error::TokenRegistryError,
+    state::{check_registry_record, Mint, TokenStatus, VerificationTier},
+    utils::{check_account_owner, check_name_account},
+};
+
+fn check_record(program_id: &Pubkey, name: &str, name_account: &AccountInfo) -> ProgramResult {
+    check_name_account(program_id, name, name_account.key)?;
+    check_account_owner(
+        name_account,
+        &spl_name_service::ID,
+        TokenRegistryError::InvalidKey,
+    )?;
+    check_registry_record(program_id, &name_account.data.borrow())
+}
+
+/// Fails unless `ticker_account` is the registry record of `ticker`, points to `mint` and its
+/// lease has not expired
+///
+/// The status of the entry is kept on the mint record, callers that must reject denylisted or
+/// flagged tokens check `TokenDataView::status` with `load_token_data`.
+pub fn assert_ticker_maps_to(
+    program_id: &Pubkey,
+    ticker: &str,
+    ticker_account: &AccountInfo,
+    mint: &Pubkey,
+) -> ProgramResult {
+    check_record(program_id, ticker, ticker_account)?;
+    let record = Mint::from_name_account(ticker_account)?;
+    if record.mint != mint.to_bytes() {
+        msg!("Ticker {} does not map to {}", ticker, mint);
+        return Err(TokenRegistryError::InvalidNameProvided.into());
+    }
+    if record.expiry != 0 && Clock::get()?.unix_timestamp >= record.expiry {
+        return Err(TokenRegistryError::LeaseExpired.into());
+    }
+    Ok(())
+}
+
+/// Borrows and parses the record data of the mint once it has been verified
+pub fn load_token_data<'a>(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    mint_name_account: &'a AccountInfo,
+) -> Result<TokenDataRef<'a>, ProgramError> {
+    check_record(program_id, &mint.to_string(), mint_name_account)?;
+    let data = Ref::map(mint_name_account.data.borrow(), |data| {
+        &data[NameRecordHeader::LEN..]
+    });
+    TokenDataView::unpack(&data)?;
+    Ok(TokenDataRef { data })
+}
+
+/// Verified record data of a mint, the account stays borrowed while it is alive
+pub struct TokenDataRef<'a> {
+    data: Ref<'a, [u8]>,
+}
+
+impl<'a> TokenDataRef<'a> {
+    /// The data was unpacked when loaded, this does not copy the strings
+    pub fn view(&self) -> TokenDataView<'_> {
+        TokenDataView::unpack(&self.data).unwrap()
+    }
+}
+
+/// Borrowed counterpart of `TokenData`
+#[derive(Debug)]
+pub struct TokenDataView<'a> {
+    pub status: TokenStatus,
+    pub tier: VerificationTier,
+    pub update_authority: &'a [u8; 32],
+    pub claim_slot: u64,
+    pub token_program: &'a [u8; 32],
+    pub extension_flags: u16,
+    pub name: &'a str,
+    pub ticker: &'a str,
+    pub mint: &'a [u8; 32],
+    pub decimals: u8,
+    pub website: &'a str,
+    pub logo_uri: &'a str,
+    pub report_count: u32,
+    pub open_report_count: u32,
+}
+
+/// Reads the borsh encoding of `TokenData` field by field
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        if self.data.len() < len {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn key(&mut self) -> Result<&'a [u8; 32], ProgramError> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, ProgramError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ProgramError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ProgramError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, ProgramError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn str(&mut self) -> Result<&'a str, ProgramError> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.take(len)?).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<'a> TokenDataView<'a> {
+    /// The zero padding of the record after the data is ignored
+    pub fn unpack(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut cursor = Cursor { data };
+        let invalid = |_| ProgramError::InvalidAccountData;
+        Ok(TokenDataView {
+            status: TokenStatus::try_from_slice(&[cursor.u8()?]).map_err(invalid)?,
+            tier: VerificationTier::try_from_slice(&[cursor.u8()?]).map_err(invalid)?,
+            update_authority: cursor.key()?,
+            claim_slot: cursor.u64()?,
+            token_program: cursor.key()?,
+            extension_flags: cursor.u16()?,
+            name: cursor.str()?,
+            ticker: cursor.str()?,
+            mint: cursor.key()?,
+            decimals: cursor.u8()?,
+            website: cursor.str()?,
+            logo_uri: cursor.str()?,
+            report_count: cursor.u32()?,
+            open_report_count: cursor.u32()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+
+    use super::*;
+    use crate::state::TokenData;
+
+    #[test]
+    fn test_unpack_matches_borsh() {
+        let mut token_data = TokenData::new(
+            VerificationTier::Community,
+            "Token".to_string(),
+            "TKN".to_string(),
+            [1; 32],
+            6,
+            "https://token.com".to_string(),
+            "https://token.com/logo.png".to_string(),
+        );
+        token_data.status = TokenStatus::Flagged;
+        token_data.update_authority = [2; 32];
+        token_data.claim_slot = 42;
+        token_data.extension_flags = 5;
+        token_data.report_count = 3;
+        token_data.open_report_count = 1;
+
+        let mut data = token_data.try_to_vec().unwrap();
+        data.resize(data.len() + TokenData::METADATA_PADDING, 0);
+        let view = TokenDataView::unpack(&data).unwrap();
+        assert_eq!(view.status, token_data.status);
+        assert_eq!(view.tier, token_data.tier);
+        assert_eq!(view.update_authority, &token_data.update_authority);
+        assert_eq!(view.claim_slot, token_data.claim_slot);
+        assert_eq!(view.token_program, &token_data.token_program);
+        assert_eq!(view.extension_flags, token_data.extension_flags);
+        assert_eq!(view.name, token_data.name);
+        assert_eq!(view.ticker, token_data.ticker);
+        assert_eq!(view.mint, &token_data.mint);
+        assert_eq!(view.decimals, token_data.decimals);
+        assert_eq!(view.website, token_data.website);
+        assert_eq!(view.logo_uri, token_data.logo_uri);
+        assert_eq!(view.report_count, token_data.report_count);
+        assert_eq!(view.open_report_count, token_data.open_report_count);
+
+        // Truncated records are rejected
+        let len = token_data.try_to_vec().unwrap().len();
+        assert!(TokenDataView::unpack(&data[..len - 1]).is_err());
+    }
+}
@@ -1,7 +1,18 @@
+use std::str::FromStr;
+
+use borsh::BorshSerialize;
+use solana_program::clock::Clock;
 use solana_program::instruction::Instruction;
-use solana_program_test::ProgramTestContext;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
 use solana_sdk::signature::Signer;
+use solana_sdk::transaction::TransactionError;
 use solana_sdk::{signature::Keypair, transaction::Transaction, transport::TransportError};
+use token_registry::entrypoint::process_instruction;
+use token_registry::state::{ADMINS, TOKEN_TLD};
 
 // Utils
 pub async fn sign_send_instructions(
@@ -17,3 +28,96 @@ pub async fn sign_send_instructions(
     transaction.partial_sign(&payer_signers, ctx.last_blockhash);
     ctx.banks_client.process_transaction(transaction).await
 }
+
+/// Simulates the instruction and returns the data set by the program, empty when none was set
+pub async fn simulate_return_data(
+    ctx: &mut ProgramTestContext,
+    instruction: Instruction,
+) -> Result<Vec<u8>, TransactionError> {
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let simulation = ctx
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap()?;
+    Ok(simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .map(|return_data| return_data.data)
+        .unwrap_or_default())
+}
+
+/// Registry and name service programs with the Token TLD and a funded admin
+pub fn registry_program_test(program_id: Pubkey, central_state: Pubkey) -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "token_registry",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_program(
+        "spl_name_service",
+        spl_name_service::id(),
+        processor!(spl_name_service::processor::Processor::process_instruction),
+    );
+    program_test.add_account(
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        Account {
+            lamports: 1_000_000,
+            data: spl_name_service::state::NameRecordHeader {
+                parent_name: Pubkey::default(),
+                owner: central_state,
+                class: Pubkey::default(),
+            }
+            .try_to_vec()
+            .unwrap(),
+            owner: spl_name_service::id(),
+            ..Account::default()
+        },
+    );
+    program_test.add_account(
+        Pubkey::from_str(ADMINS.get(0).unwrap()).unwrap(),
+        Account {
+            lamports: 10_000_000,
+            ..Account::default()
+        },
+    );
+    program_test
+}
+
+pub fn mint_account(mint_authority: COption<Pubkey>, decimals: u8, owner: Pubkey) -> Account {
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint {
+        mint_authority,
+        supply: 0,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner,
+        ..Account::default()
+    }
+}
+
+pub fn funded_account() -> Account {
+    Account {
+        lamports: 10_000_000_000,
+        ..Account::default()
+    }
+}
+
+/// Moves the clock of the test validator, the slot is left unchanged
+pub async fn set_unix_timestamp(ctx: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = ctx.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    ctx.set_sysvar(&clock);
+}
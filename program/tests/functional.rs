@@ -1,69 +1,120 @@
-use borsh::BorshSerialize;
-use solana_program::hash::hashv;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::clock::Clock;
+use solana_program::instruction::InstructionError;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
-use solana_program_test::{processor, ProgramTest};
+use solana_program::rent::Rent;
+use solana_program::system_program;
 use solana_sdk::account::Account;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signer;
+use solana_sdk::transaction::TransactionError;
 use std::str::FromStr;
-use token_registry::entrypoint::process_instruction;
-use token_registry::instruction::{init, register};
-use token_registry::state::ADMINS;
+use token_registry::derivation::{
+    derive_central_state, derive_legacy_mint_account, derive_legacy_ticker_account,
+    derive_mint_account, derive_ticker_account,
+};
+use token_registry::error::TokenRegistryError;
+use token_registry::instruction::{
+    add_attester, allow_mint, approve_application, approve_claimant, attest, bid, cancel_listing,
+    challenge, claim_entry, deny_mint, emit, finalize_listing, init, list_with_bond,
+    migrate_central_state, migrate_entry, reclaim, refresh_extension_flags, refund_auction,
+    register, register_v1, reject_application, release, remove_attester, renew, report, reserve,
+    resolve_challenge, resolve_mint, resolve_report, resolve_ticker, revoke, set_bond_terms,
+    set_fee_schedule, set_lease_terms, set_update_authority, set_verification_tier, settle_auction,
+    start_auction, submit_application, sync_from_metaplex, update_metadata, withdraw_fees,
+};
+use token_registry::metaplex::{self, Metadata};
 use token_registry::state::TOKEN_TLD;
-
-use spl_name_service::state::{get_seeds_and_key, HASH_PREFIX};
+use token_registry::state::{
+    check_registry_record, valid_attestations, Application, Attestation, AttestationKind, Attester,
+    Auction, BondedListing, CentralState, ClaimApproval, DeniedMint, Mint, Report, ReportReason,
+    ReservedTicker, TickerRecord, TokenRecord, TokenStatus, TokenSummary, Tombstone,
+    VerificationTier, FEE_TIERS,
+};
+use token_registry::token_2022;
+use token_registry::token_metadata::TokenMetadata;
 
 pub mod common;
 
-use crate::common::utils::sign_send_instructions;
+use crate::common::utils::{
+    funded_account, mint_account, registry_program_test, set_unix_timestamp,
+    sign_send_instructions, simulate_return_data,
+};
 
 #[tokio::test]
 async fn test() {
     // Create program and test environment
     let token_registry_program_id = Pubkey::new_unique();
 
-    let mut program_test = ProgramTest::new(
-        "token_registry",
-        token_registry_program_id,
-        processor!(process_instruction),
-    );
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
 
-    // Add name service
-    program_test.add_program(
-        "spl_name_service",
-        spl_name_service::id(),
-        processor!(spl_name_service::processor::Processor::process_instruction),
-    );
+    // Add mints, FIDA is a Token-2022 mint and USDC is used for self-registrations
+    let mint_name = "EchesyfXePKdLtoiZSL8pBe8Myagyy8ZRqsACNCFGnvp";
+    // The FIDA mint has a permanent delegate and freezes new accounts by default
+    let mut fida_mint_account = mint_account(COption::None, 6, token_2022::ID);
+    fida_mint_account
+        .data
+        .resize(token_2022::ACCOUNT_TYPE_OFFSET, 0);
+    fida_mint_account.data.push(token_2022::MINT_ACCOUNT_TYPE);
+    fida_mint_account
+        .data
+        .extend_from_slice(&12u16.to_le_bytes());
+    fida_mint_account
+        .data
+        .extend_from_slice(&32u16.to_le_bytes());
+    fida_mint_account
+        .data
+        .extend_from_slice(&Pubkey::new_unique().to_bytes());
+    fida_mint_account
+        .data
+        .extend_from_slice(&6u16.to_le_bytes());
+    fida_mint_account
+        .data
+        .extend_from_slice(&1u16.to_le_bytes());
+    fida_mint_account.data.push(2);
+    program_test.add_account(Pubkey::from_str(mint_name).unwrap(), fida_mint_account);
 
-    let (state_key, nonce) = Pubkey::find_program_address(
-        &[&token_registry_program_id.to_bytes()],
-        &token_registry_program_id,
+    let wrapped_sol_mint = "So11111111111111111111111111111111111111112";
+    program_test.add_account(
+        Pubkey::from_str(wrapped_sol_mint).unwrap(),
+        mint_account(COption::None, 9, spl_token::ID),
     );
 
-    let root_domain_data = spl_name_service::state::NameRecordHeader {
-        parent_name: Pubkey::default(),
-        owner: state_key,
-        class: Pubkey::default(),
-    }
-    .try_to_vec()
-    .unwrap();
-
-    // Add token TLD
+    let usdc_mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    let usdc_authority = Keypair::new();
     program_test.add_account(
-        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        usdc_authority.pubkey(),
         Account {
-            lamports: 1_000_000,
-            data: root_domain_data,
-            owner: spl_name_service::id(),
+            lamports: 10_000_000_000,
             ..Account::default()
         },
     );
+    program_test.add_account(
+        Pubkey::from_str(usdc_mint).unwrap(),
+        mint_account(COption::Some(usdc_authority.pubkey()), 6, spl_token::ID),
+    );
 
-    // Add admin
+    // USDC has Metaplex metadata, the strings are zero padded
+    let (usdc_metadata_key, _) = Metadata::find_key(&Pubkey::from_str(usdc_mint).unwrap());
+    let pad = |s: &str, len: usize| format!("{}{}", s, "\0".repeat(len - s.len()));
     program_test.add_account(
-        Pubkey::from_str(ADMINS.get(0).unwrap()).unwrap(),
+        usdc_metadata_key,
         Account {
-            lamports: 10_000_000,
+            lamports: 1_000_000_000,
+            data: (
+                metaplex::METADATA_KEY,
+                usdc_authority.pubkey().to_bytes(),
+                Pubkey::from_str(usdc_mint).unwrap().to_bytes(),
+                pad("USD Coin", 32),
+                pad("USDC", 10),
+                pad("https://usdc.com/metadata.json", 200),
+            )
+                .try_to_vec()
+                .unwrap(),
+            owner: metaplex::ID,
             ..Account::default()
         },
     );
@@ -87,53 +138,2545 @@ async fn test() {
         .unwrap();
 
     let ticker_name = "FIDA";
-    let mint_name = "EchesyfXePKdLtoiZSL8pBe8Myagyy8ZRqsACNCFGnvp";
-
-    let hashed_name_ticker = hashv(&[(HASH_PREFIX.to_owned() + ticker_name).as_bytes()])
-        .0
-        .to_vec();
+    let name_ticker_account = derive_ticker_account(&token_registry_program_id, ticker_name);
+    let name_mint_account = derive_mint_account(
+        &token_registry_program_id,
+        &Pubkey::from_str(mint_name).unwrap(),
+    );
 
-    println!("Hashed name length {:?}", hashed_name_ticker.len());
+    let (sol_treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
 
-    let (name_ticker_account, _) = get_seeds_and_key(
-        &spl_name_service::id(),
-        hashed_name_ticker.clone(),
+    let register_instruction = register(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        Pubkey::from_str(mint_name).unwrap(),
+        system_program::ID,
+        prg_test_ctx.payer.pubkey(),
         None,
-        Some(&Pubkey::from_str(TOKEN_TLD).unwrap()),
+        token_registry::instruction::register::Params {
+            signer_index: Some(0),
+            name: "Bonfida Token".to_string(),
+            ticker: ticker_name.to_string(),
+            mint: mint_name.to_string(),
+            decimals: 6,
+            website: "".to_string(),
+            logo_uri: "".to_string(),
+            update_authority: None,
+        },
     );
 
-    let hashed_name_mint = hashv(&[(HASH_PREFIX.to_owned() + mint_name).as_bytes()])
-        .0
-        .to_vec();
+    sign_send_instructions(&mut prg_test_ctx, vec![register_instruction], vec![])
+        .await
+        .unwrap();
+
+    // Reserved tickers cannot be registered until released
+    let (reserved_ticker_account, _) = ReservedTicker::find_key(&token_registry_program_id, "SOL");
 
-    println!("Hashed name length {:?}", hashed_name_mint.len());
+    let reserve_instruction = reserve(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        reserved_ticker_account,
+        token_registry::instruction::reserve::Params {
+            signer_index: 0,
+            ticker: "SOL".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![reserve_instruction], vec![])
+        .await
+        .unwrap();
 
-    let (name_mint_account, _) = get_seeds_and_key(
-        &spl_name_service::id(),
-        hashed_name_mint.clone(),
-        None,
-        Some(&Pubkey::from_str(TOKEN_TLD).unwrap()),
+    let payer = prg_test_ctx.payer.pubkey();
+    let register_wrapped_sol = |ticker: &str| {
+        register(
+            token_registry_program_id,
+            payer,
+            wrapped_sol_mint,
+            system_program::ID,
+            payer,
+            None,
+            token_registry::instruction::register::Params {
+                signer_index: Some(0),
+                name: "Wrapped SOL".to_string(),
+                ticker: ticker.to_string(),
+                mint: wrapped_sol_mint.to_string(),
+                decimals: 9,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+                update_authority: None,
+            },
+        )
+    };
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![register_wrapped_sol("SOL")], vec![])
+            .await
+            .is_err()
     );
 
-    let register_instruction = register(
+    let release_instruction = release(
         token_registry_program_id,
         prg_test_ctx.payer.pubkey(),
-        name_ticker_account,
+        reserved_ticker_account,
+        token_registry::instruction::release::Params {
+            signer_index: 0,
+            ticker: "SOL".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![release_instruction], vec![])
+        .await
+        .unwrap();
+    // Tickers are registered under their normalized form only
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![register_wrapped_sol("sol")], vec![])
+            .await
+            .is_err()
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![register_wrapped_sol("SOL")], vec![])
+        .await
+        .unwrap();
+
+    // Denylisting a registered mint flags its entry
+    let deny_mint_instruction = deny_mint(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        DeniedMint::find_key(
+            &token_registry_program_id,
+            &Pubkey::from_str(mint_name).unwrap(),
+        )
+        .0,
         name_mint_account,
         Pubkey::from_str(TOKEN_TLD).unwrap(),
         state_key,
-        token_registry::instruction::register::Params {
+        derive_legacy_mint_account(&Pubkey::from_str(mint_name).unwrap()),
+        token_registry::instruction::deny_mint::Params {
             signer_index: 0,
-            name: "Bonfida Token".to_string(),
+            mint: mint_name.to_string(),
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![deny_mint_instruction.clone()],
+        vec![],
+    )
+    .await
+    .unwrap();
+    let token_data = |data: &[u8]| {
+        TokenRecord::from_account_data(&token_registry_program_id, data)
+            .unwrap()
+            .data
+    };
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(name_mint_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    assert_eq!(
+        token_data(&mint_name_account_data).status,
+        TokenStatus::Denylisted
+    );
+
+    // Allowing the mint again lifts the flag and closes the denylist entry
+    let (denied_mint_account, _) = DeniedMint::find_key(
+        &token_registry_program_id,
+        &Pubkey::from_str(mint_name).unwrap(),
+    );
+    let allow_mint_instruction = allow_mint(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        denied_mint_account,
+        name_mint_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        token_registry::instruction::allow_mint::Params {
+            signer_index: 0,
+            mint: mint_name.to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![allow_mint_instruction], vec![])
+        .await
+        .unwrap();
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(denied_mint_account)
+        .await
+        .unwrap()
+        .is_none());
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(name_mint_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    assert_eq!(
+        token_data(&mint_name_account_data).status,
+        TokenStatus::Active
+    );
+
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![deny_mint_instruction], vec![])
+        .await
+        .unwrap();
+
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(name_mint_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.status, TokenStatus::Denylisted);
+    check_registry_record(&token_registry_program_id, &mint_name_account_data).unwrap();
+    assert_eq!(token_data.tier, VerificationTier::Verified);
+    assert_eq!(token_data.token_program, token_2022::ID.to_bytes());
+
+    let ticker_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(name_ticker_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let ticker_record =
+        TickerRecord::from_account_data(&token_registry_program_id, &ticker_name_account_data)
+            .unwrap();
+    assert_eq!(
+        ticker_record.data.mint,
+        Pubkey::from_str(mint_name).unwrap().to_bytes()
+    );
+    assert_eq!(ticker_record.header.owner, state_key);
+    let fida_flags = token_2022::FLAG_PERMANENT_DELEGATE | token_2022::FLAG_DEFAULT_FROZEN;
+    assert_eq!(token_data.extension_flags, fida_flags);
+
+    // Anyone can refresh the extension flags of a mint
+    let refresh_instruction = refresh_extension_flags(
+        token_registry_program_id,
+        name_mint_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        Pubkey::from_str(mint_name).unwrap(),
+        token_registry::instruction::refresh_extension_flags::Params {
+            mint: mint_name.to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![refresh_instruction], vec![])
+        .await
+        .unwrap();
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(name_mint_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.extension_flags, fida_flags);
+
+    // The registry answers the token-metadata interface for mint records
+    let emit_instruction = emit(
+        token_registry_program_id,
+        name_mint_account,
+        token_registry::instruction::emit::Params {
+            start: None,
+            end: None,
+        },
+    );
+    let return_data = simulate_return_data(&mut prg_test_ctx, emit_instruction)
+        .await
+        .unwrap();
+    let expected_metadata = TokenMetadata {
+        update_authority: [0; 32],
+        mint: Pubkey::from_str(mint_name).unwrap().to_bytes(),
+        name: "Bonfida Token".to_string(),
+        symbol: ticker_name.to_string(),
+        uri: "".to_string(),
+        additional_metadata: vec![],
+    };
+    let expected_bytes = expected_metadata.try_to_vec().unwrap();
+    assert_eq!(return_data, expected_bytes);
+    assert_eq!(
+        TokenMetadata::try_from_slice(&return_data).unwrap(),
+        expected_metadata
+    );
+
+    // A slice of the metadata can be requested, here the mint
+    let emit_instruction = emit(
+        token_registry_program_id,
+        name_mint_account,
+        token_registry::instruction::emit::Params {
+            start: Some(32),
+            end: Some(64),
+        },
+    );
+    let return_data = simulate_return_data(&mut prg_test_ctx, emit_instruction)
+        .await
+        .unwrap();
+    assert_eq!(return_data, expected_bytes[32..64].to_vec());
+
+    let emit_instruction = emit(
+        token_registry_program_id,
+        name_ticker_account,
+        token_registry::instruction::emit::Params {
+            start: None,
+            end: None,
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![emit_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Other programs resolve tickers and mints through CPI
+    let resolve_instruction = resolve_ticker(
+        token_registry_program_id,
+        name_ticker_account,
+        token_registry::instruction::resolve_ticker::Params {
             ticker: ticker_name.to_string(),
+        },
+    );
+    let return_data = simulate_return_data(&mut prg_test_ctx, resolve_instruction)
+        .await
+        .unwrap();
+    let resolved_ticker = Mint::try_from_slice(&return_data).unwrap();
+    assert_eq!(
+        resolved_ticker.mint,
+        Pubkey::from_str(mint_name).unwrap().to_bytes()
+    );
+    assert_eq!(resolved_ticker.expiry, ticker_record.data.expiry);
+
+    let resolve_instruction = resolve_mint(
+        token_registry_program_id,
+        name_mint_account,
+        token_registry::instruction::resolve_mint::Params {
             mint: mint_name.to_string(),
+        },
+    );
+    let return_data = simulate_return_data(&mut prg_test_ctx, resolve_instruction)
+        .await
+        .unwrap();
+    let summary = TokenSummary::try_from_slice(&return_data).unwrap();
+    assert_eq!(
+        summary.mint,
+        Pubkey::from_str(mint_name).unwrap().to_bytes()
+    );
+    assert_eq!(summary.decimals, 6);
+    assert_eq!(summary.status, TokenStatus::Denylisted);
+    assert_eq!(summary.tier, VerificationTier::Verified);
+    assert_eq!(summary.extension_flags, fida_flags);
+
+    let resolve_instruction = resolve_ticker(
+        token_registry_program_id,
+        name_mint_account,
+        token_registry::instruction::resolve_ticker::Params {
+            ticker: ticker_name.to_string(),
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![resolve_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Anyone can register once a fee is configured, short tickers are more expensive
+    let registration_fees = [
+        100_000_000_000,
+        50_000_000_000,
+        10_000_000_000,
+        5_000_000_000,
+        1_000_000_000,
+    ];
+    let set_fee_schedule_instruction = set_fee_schedule(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        state_key,
+        sol_treasury,
+        token_registry::instruction::set_fee_schedule::Params {
+            signer_index: 0,
+            fee_mint: system_program::ID,
+            registration_fees,
+            application_deposit: 0,
+            rejection_fee: 0,
+            report_deposit: 0,
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![set_fee_schedule_instruction],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    let usdc_name_account = derive_mint_account(
+        &token_registry_program_id,
+        &Pubkey::from_str(usdc_mint).unwrap(),
+    );
+    let register_instruction = register(
+        token_registry_program_id,
+        usdc_authority.pubkey(),
+        usdc_mint,
+        system_program::ID,
+        usdc_authority.pubkey(),
+        Some(usdc_metadata_key),
+        token_registry::instruction::register::Params {
+            signer_index: None,
+            name: "".to_string(),
+            ticker: "USDC".to_string(),
+            mint: usdc_mint.to_string(),
             decimals: 6,
             website: "".to_string(),
             logo_uri: "".to_string(),
+            update_authority: None,
         },
     );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_instruction],
+        vec![&usdc_authority],
+    )
+    .await
+    .unwrap();
 
-    sign_send_instructions(&mut prg_test_ctx, vec![register_instruction], vec![])
+    let treasury_balance = prg_test_ctx
+        .banks_client
+        .get_balance(sol_treasury)
+        .await
+        .unwrap();
+    assert_eq!(treasury_balance, registration_fees[3]);
+
+    // Admins withdraw the collected fees from the treasury
+    let fee_destination = Pubkey::new_unique();
+    let withdrawn_amount = registration_fees[3] - 1_000_000_000;
+    let withdraw_fees_instruction = withdraw_fees(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        state_key,
+        sol_treasury,
+        fee_destination,
+        token_registry::instruction::withdraw_fees::Params {
+            signer_index: 0,
+            fee_mint: system_program::ID,
+            amount: withdrawn_amount,
+        },
+    );
+
+    // The admin has to sign
+    let mut unsigned_instruction = withdraw_fees_instruction.clone();
+    unsigned_instruction.accounts[2].is_signer = false;
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![unsigned_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Signers outside of the admin list are rejected
+    #[cfg(not(feature = "test-bpf"))]
+    {
+        let withdraw_fees_instruction = withdraw_fees(
+            token_registry_program_id,
+            usdc_authority.pubkey(),
+            state_key,
+            sol_treasury,
+            usdc_authority.pubkey(),
+            token_registry::instruction::withdraw_fees::Params {
+                signer_index: 0,
+                fee_mint: system_program::ID,
+                amount: withdrawn_amount,
+            },
+        );
+        assert!(sign_send_instructions(
+            &mut prg_test_ctx,
+            vec![withdraw_fees_instruction],
+            vec![&usdc_authority]
+        )
+        .await
+        .is_err());
+    }
+
+    sign_send_instructions(&mut prg_test_ctx, vec![withdraw_fees_instruction], vec![])
+        .await
+        .unwrap();
+    let treasury_balance = prg_test_ctx
+        .banks_client
+        .get_balance(sol_treasury)
+        .await
+        .unwrap();
+    assert_eq!(treasury_balance, registration_fees[3] - withdrawn_amount);
+    let destination_balance = prg_test_ctx
+        .banks_client
+        .get_balance(fee_destination)
+        .await
+        .unwrap();
+    assert_eq!(destination_balance, withdrawn_amount);
+
+    // The empty name was filled in from the Metaplex metadata, syncing again keeps it, the uri
+    // points to the metadata document and is not used as the logo uri
+    let sync_instruction = sync_from_metaplex(
+        token_registry_program_id,
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        usdc_metadata_key,
+        token_registry::instruction::sync_from_metaplex::Params {
+            mint: usdc_mint.to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![sync_instruction], vec![])
+        .await
+        .unwrap();
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(usdc_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.name, "USD Coin");
+    assert!(token_data.logo_uri.is_empty());
+
+    // Reports are counted on the mint record until a curator resolves them
+    let (report_account, _) = Report::find_key(
+        &token_registry_program_id,
+        &Pubkey::from_str(usdc_mint).unwrap(),
+        &prg_test_ctx.payer.pubkey(),
+    );
+    let report_instruction = report(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        report_account,
+        token_registry::instruction::report::Params {
+            mint: usdc_mint.to_string(),
+            reason: ReportReason::Impersonation,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![report_instruction], vec![])
+        .await
+        .unwrap();
+
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(usdc_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.tier, VerificationTier::Unverified);
+    assert_eq!(token_data.report_count, 1);
+    assert_eq!(token_data.open_report_count, 1);
+
+    let resolve_report_instruction = resolve_report(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        report_account,
+        prg_test_ctx.payer.pubkey(),
+        sol_treasury,
+        token_registry::instruction::resolve_report::Params {
+            signer_index: 0,
+            flag_token: true,
+            refund_deposit: false,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![resolve_report_instruction], vec![])
+        .await
+        .unwrap();
+
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(usdc_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.status, TokenStatus::Flagged);
+    assert_eq!(token_data.report_count, 1);
+    assert_eq!(token_data.open_report_count, 0);
+
+    // Registered attesters can attest to listed tokens
+    let attester_account =
+        Attester::find_key(&token_registry_program_id, &prg_test_ctx.payer.pubkey()).0;
+    let add_attester_instruction = add_attester(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        attester_account,
+        token_registry::instruction::add_attester::Params {
+            signer_index: 0,
+            attester: prg_test_ctx.payer.pubkey(),
+            name: "Auditor".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![add_attester_instruction], vec![])
+        .await
+        .unwrap();
+
+    let (attestation_account, _) = Attestation::find_key(
+        &token_registry_program_id,
+        &Pubkey::from_str(usdc_mint).unwrap(),
+        &prg_test_ctx.payer.pubkey(),
+    );
+    let attest_instruction = attest(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        attester_account,
+        usdc_name_account,
+        attestation_account,
+        token_registry::instruction::attest::Params {
+            mint: usdc_mint.to_string(),
+            kind: AttestationKind::Audit,
+            expiry: 0,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![attest_instruction.clone()], vec![])
+        .await
+        .unwrap();
+
+    let mut program_accounts = vec![];
+    for key in [attester_account, attestation_account] {
+        let account = prg_test_ctx
+            .banks_client
+            .get_account(key)
+            .await
+            .unwrap()
+            .unwrap();
+        program_accounts.push(account.data);
+    }
+    let attestations = valid_attestations(
+        &Pubkey::from_str(usdc_mint).unwrap(),
+        program_accounts.iter().map(|d| &d[..]),
+        0,
+    );
+    assert_eq!(attestations.len(), 1);
+    assert_eq!(attestations[0].kind, AttestationKind::Audit);
+
+    // Attesters revoke their attestations, which closes them
+    let revoke_instruction = revoke(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        attestation_account,
+        token_registry::instruction::revoke::Params {
+            mint: Pubkey::from_str(usdc_mint).unwrap(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![revoke_instruction.clone()], vec![])
+        .await
+        .unwrap();
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(attestation_account)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Attestations of a removed attester are no longer valid and it can no longer attest
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![attest_instruction.clone()], vec![])
+        .await
+        .unwrap();
+    let remove_attester_instruction = remove_attester(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        attester_account,
+        token_registry::instruction::remove_attester::Params {
+            signer_index: 0,
+            attester: prg_test_ctx.payer.pubkey(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![remove_attester_instruction], vec![])
+        .await
+        .unwrap();
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(attester_account)
+        .await
+        .unwrap()
+        .is_none());
+    let attestation_data = prg_test_ctx
+        .banks_client
+        .get_account(attestation_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let attestations = valid_attestations(
+        &Pubkey::from_str(usdc_mint).unwrap(),
+        vec![&attestation_data[..]],
+        0,
+    );
+    assert!(attestations.is_empty());
+
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![revoke_instruction], vec![])
         .await
         .unwrap();
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![attest_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Delegated update authorities can only update the metadata of their token
+    let set_update_authority_instruction = set_update_authority(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        token_registry::instruction::set_update_authority::Params {
+            signer_index: 0,
+            mint: usdc_mint.to_string(),
+            update_authority: Some(usdc_authority.pubkey()),
+        },
+    );
+    let update_metadata_instruction = update_metadata(
+        token_registry_program_id,
+        usdc_authority.pubkey(),
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        token_registry::instruction::update_metadata::Params {
+            signer_index: None,
+            mint: usdc_mint.to_string(),
+            name: Some("Circle".to_string()),
+            website: Some("https://www.circle.com/usdc".to_string()),
+            logo_uri: None,
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            set_update_authority_instruction,
+            update_metadata_instruction,
+        ],
+        vec![&usdc_authority],
+    )
+    .await
+    .unwrap();
+
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(usdc_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.website, "https://www.circle.com/usdc");
+    assert_eq!(token_data.ticker, "USDC");
+
+    // Syncing only fills empty fields and cannot revert the update
+    let sync_instruction = sync_from_metaplex(
+        token_registry_program_id,
+        usdc_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        usdc_metadata_key,
+        token_registry::instruction::sync_from_metaplex::Params {
+            mint: usdc_mint.to_string(),
+        },
+    );
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![sync_instruction], vec![])
+        .await
+        .unwrap();
+    let mint_name_account_data = prg_test_ctx
+        .banks_client
+        .get_account(usdc_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data =
+        TokenRecord::from_account_data(&token_registry_program_id, &mint_name_account_data)
+            .unwrap()
+            .data;
+    assert_eq!(token_data.name, "Circle");
+    assert!(token_data.logo_uri.is_empty());
+}
+
+#[tokio::test]
+async fn test_migrate_central_state() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    // Central state created by the first version of the program, only the nonce is stored
+    program_test.add_account(
+        state_key,
+        Account {
+            lamports: Rent::default().minimum_balance(1),
+            data: vec![nonce],
+            owner: token_registry_program_id,
+            ..Account::default()
+        },
+    );
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+
+    let set_lease_terms_instruction = |grace_period: i64| {
+        set_lease_terms(
+            token_registry_program_id,
+            payer,
+            state_key,
+            token_registry::instruction::set_lease_terms::Params {
+                signer_index: 0,
+                lease_duration: 1_000,
+                grace_period,
+                ticker_cooldown: 0,
+            },
+        )
+    };
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![set_lease_terms_instruction(1)],
+        vec![]
+    )
+    .await
+    .is_err());
+
+    let migrate_instruction = migrate_central_state(
+        token_registry_program_id,
+        prg_test_ctx.payer.pubkey(),
+        state_key,
+        token_registry::instruction::migrate_central_state::Params {},
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![migrate_instruction.clone(), set_lease_terms_instruction(2)],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    let central_state_account = prg_test_ctx
+        .banks_client
+        .get_account(state_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(central_state_account.data.len(), CentralState::LEN);
+    assert!(central_state_account.lamports >= Rent::default().minimum_balance(CentralState::LEN));
+    let central_state = CentralState::unpack_from_slice(&central_state_account.data).unwrap();
+    assert_eq!(central_state.signer_nonce, nonce);
+    assert_eq!(central_state.fee_mint, system_program::ID.to_bytes());
+    assert_eq!(central_state.lease_duration, 1_000);
+    assert_eq!(central_state.grace_period, 2);
+
+    // Migrated central states cannot be migrated again
+    prg_test_ctx.last_blockhash = prg_test_ctx
+        .banks_client
+        .get_new_latest_blockhash(&prg_test_ctx.last_blockhash)
+        .await
+        .unwrap();
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![migrate_instruction], vec![])
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_legacy_records() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let legacy_mint = Pubkey::new_unique();
+    let new_mint = Pubkey::new_unique();
+    program_test.add_account(legacy_mint, mint_account(COption::None, 6, spl_token::ID));
+    program_test.add_account(new_mint, mint_account(COption::None, 6, spl_token::ID));
+
+    // Records issued before the central state became the class of the name records
+    let legacy_record = |data: Vec<u8>| {
+        let mut record = spl_name_service::state::NameRecordHeader {
+            parent_name: Pubkey::from_str(TOKEN_TLD).unwrap(),
+            owner: state_key,
+            class: Pubkey::default(),
+        }
+        .try_to_vec()
+        .unwrap();
+        record.extend(data);
+        Account {
+            lamports: 1_000_000_000,
+            data: record,
+            owner: spl_name_service::ID,
+            ..Account::default()
+        }
+    };
+    let legacy_entry = |ticker: &str, mint: &Pubkey| {
+        [
+            (
+                derive_legacy_ticker_account(ticker),
+                legacy_record(mint.to_bytes().to_vec()),
+            ),
+            (
+                derive_legacy_mint_account(mint),
+                legacy_record(
+                    (
+                        "Legacy Token".to_string(),
+                        ticker.to_string(),
+                        mint.to_bytes(),
+                        6u8,
+                        "".to_string(),
+                        "".to_string(),
+                    )
+                        .try_to_vec()
+                        .unwrap(),
+                ),
+            ),
+        ]
+    };
+    let denied_legacy_mint = Pubkey::new_unique();
+    program_test.add_account(
+        denied_legacy_mint,
+        mint_account(COption::None, 6, spl_token::ID),
+    );
+    // Legacy tickers were stored as given
+    let lowercase_legacy_mint = Pubkey::new_unique();
+    program_test.add_account(
+        lowercase_legacy_mint,
+        mint_account(COption::None, 6, spl_token::ID),
+    );
+    for (key, account) in legacy_entry("LEGACY", &legacy_mint)
+        .iter()
+        .chain(legacy_entry("DENIED", &denied_legacy_mint).iter())
+        .chain(legacy_entry("lower", &lowercase_legacy_mint).iter())
+    {
+        program_test.add_account(*key, account.clone());
+    }
+    // Denylisted before the checks of `MigrateEntry`
+    program_test.add_account(
+        DeniedMint::find_key(&token_registry_program_id, &denied_legacy_mint).0,
+        Account {
+            lamports: 1_000_000_000,
+            data: DeniedMint::new(denied_legacy_mint.to_bytes())
+                .try_to_vec()
+                .unwrap(),
+            owner: token_registry_program_id,
+            ..Account::default()
+        },
+    );
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+
+    let init_instruction = init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![init_instruction], vec![])
+        .await
+        .unwrap();
+
+    let register_instruction = |ticker: &str, mint: &Pubkey| {
+        register(
+            token_registry_program_id,
+            payer,
+            *mint,
+            system_program::ID,
+            payer,
+            None,
+            token_registry::instruction::register::Params {
+                signer_index: Some(0),
+                name: "Token".to_string(),
+                ticker: ticker.to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+                update_authority: None,
+            },
+        )
+    };
+
+    // Neither the legacy ticker nor the legacy mint can be registered again
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_instruction("LEGACY", &new_mint)],
+        vec![]
+    )
+    .await
+    .is_err());
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_instruction("NEW", &legacy_mint)],
+        vec![]
+    )
+    .await
+    .is_err());
+
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_instruction("NEW", &new_mint)],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(derive_ticker_account(&token_registry_program_id, "NEW"))
+        .await
+        .unwrap()
+        .is_some());
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(derive_legacy_ticker_account("NEW"))
+        .await
+        .unwrap()
+        .is_none());
+
+    // Legacy records are read in the current layout
+    let legacy_mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_legacy_mint_account(&legacy_mint))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let legacy_record =
+        TokenRecord::from_account_data(&token_registry_program_id, &legacy_mint_data).unwrap();
+    assert!(legacy_record.is_legacy());
+    assert_eq!(legacy_record.data.ticker, "LEGACY");
+    assert_eq!(legacy_record.data.status, TokenStatus::Active);
+    assert_eq!(legacy_record.data.tier, VerificationTier::Verified);
+    assert!(check_registry_record(&token_registry_program_id, &legacy_mint_data).is_err());
+
+    // Legacy entries must be migrated before they can be denylisted
+    let deny_mint_instruction = deny_mint(
+        token_registry_program_id,
+        payer,
+        DeniedMint::find_key(&token_registry_program_id, &legacy_mint).0,
+        derive_mint_account(&token_registry_program_id, &legacy_mint),
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        derive_legacy_mint_account(&legacy_mint),
+        token_registry::instruction::deny_mint::Params {
+            signer_index: 0,
+            mint: legacy_mint.to_string(),
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![deny_mint_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Denylisted mints are not re-issued
+    let migrate_instruction = migrate_entry(
+        token_registry_program_id,
+        payer,
+        denied_legacy_mint,
+        token_registry::instruction::migrate_entry::Params {
+            ticker: "DENIED".to_string(),
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![migrate_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Migration re-issues the entry at the current addresses and deletes the legacy records
+    let migrate_instruction = migrate_entry(
+        token_registry_program_id,
+        payer,
+        legacy_mint,
+        token_registry::instruction::migrate_entry::Params {
+            ticker: "LEGACY".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![migrate_instruction], vec![])
+        .await
+        .unwrap();
+
+    for legacy_key in [
+        derive_legacy_ticker_account("LEGACY"),
+        derive_legacy_mint_account(&legacy_mint),
+    ] {
+        assert!(prg_test_ctx
+            .banks_client
+            .get_account(legacy_key)
+            .await
+            .unwrap()
+            .is_none());
+    }
+    let ticker_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_ticker_account(&token_registry_program_id, "LEGACY"))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let ticker_record =
+        TickerRecord::from_account_data(&token_registry_program_id, &ticker_data).unwrap();
+    assert!(!ticker_record.is_legacy());
+    assert_eq!(ticker_record.data.mint, legacy_mint.to_bytes());
+    assert_eq!(ticker_record.data.expiry, 0);
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_mint_account(
+            &token_registry_program_id,
+            &legacy_mint,
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    check_registry_record(&token_registry_program_id, &mint_data).unwrap();
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.name, "Legacy Token");
+    assert_eq!(token_data.tier, VerificationTier::Verified);
+    assert_eq!(token_data.token_program, spl_token::ID.to_bytes());
+
+    // Lowercase legacy tickers are re-issued under their normalized form
+    let migrate_instruction = migrate_entry(
+        token_registry_program_id,
+        payer,
+        lowercase_legacy_mint,
+        token_registry::instruction::migrate_entry::Params {
+            ticker: "lower".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![migrate_instruction], vec![])
+        .await
+        .unwrap();
+    let ticker_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_ticker_account(&token_registry_program_id, "LOWER"))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let ticker_record =
+        TickerRecord::from_account_data(&token_registry_program_id, &ticker_data).unwrap();
+    assert_eq!(ticker_record.data.mint, lowercase_legacy_mint.to_bytes());
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(derive_ticker_account(&token_registry_program_id, "lower"))
+        .await
+        .unwrap()
+        .is_none());
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_mint_account(
+            &token_registry_program_id,
+            &lowercase_legacy_mint,
+        ))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.ticker, "LOWER");
+
+    // The original `Register` instruction keeps writing legacy records
+    let register_v1_instruction = |ticker: &str, mint: &Pubkey| {
+        register_v1(
+            token_registry_program_id,
+            payer,
+            *mint,
+            token_registry::instruction::register_v1::Params {
+                signer_index: 0,
+                ticker: ticker.to_string(),
+                name: "Token".to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+            },
+        )
+    };
+    // The checks of `RegisterV2` apply: names registered at the current addresses, reserved
+    // tickers and denylisted mints are rejected
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_v1_instruction("NEW", &Pubkey::new_unique())],
+        vec![]
+    )
+    .await
+    .is_err());
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_v1_instruction("FRESH", &new_mint)],
+        vec![]
+    )
+    .await
+    .is_err());
+    let denied_mint = Pubkey::new_unique();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            reserve(
+                token_registry_program_id,
+                payer,
+                ReservedTicker::find_key(&token_registry_program_id, "KEPT").0,
+                token_registry::instruction::reserve::Params {
+                    signer_index: 0,
+                    ticker: "KEPT".to_string(),
+                },
+            ),
+            deny_mint(
+                token_registry_program_id,
+                payer,
+                DeniedMint::find_key(&token_registry_program_id, &denied_mint).0,
+                derive_mint_account(&token_registry_program_id, &denied_mint),
+                Pubkey::from_str(TOKEN_TLD).unwrap(),
+                state_key,
+                derive_legacy_mint_account(&denied_mint),
+                token_registry::instruction::deny_mint::Params {
+                    signer_index: 0,
+                    mint: denied_mint.to_string(),
+                },
+            ),
+        ],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_v1_instruction("KEPT", &Pubkey::new_unique())],
+        vec![]
+    )
+    .await
+    .is_err());
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_v1_instruction("BANNED", &denied_mint)],
+        vec![]
+    )
+    .await
+    .is_err());
+
+    let old_mint = Pubkey::new_unique();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![register_v1_instruction("OLD", &old_mint)],
+        vec![],
+    )
+    .await
+    .unwrap();
+    let old_mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_legacy_mint_account(&old_mint))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let old_record =
+        TokenRecord::from_account_data(&token_registry_program_id, &old_mint_data).unwrap();
+    assert!(old_record.is_legacy());
+    assert_eq!(old_record.data.ticker, "OLD");
+}
+
+#[tokio::test]
+async fn test_auction() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let winner = Keypair::new();
+    let outbid = Keypair::new();
+    program_test.add_account(winner.pubkey(), funded_account());
+    program_test.add_account(outbid.pubkey(), funded_account());
+    let winner_mint = Pubkey::new_unique();
+    let other_mint = Pubkey::new_unique();
+    program_test.add_account(
+        winner_mint,
+        mint_account(COption::Some(winner.pubkey()), 6, spl_token::ID),
+    );
+    program_test.add_account(
+        other_mint,
+        mint_account(COption::Some(outbid.pubkey()), 6, spl_token::ID),
+    );
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+
+    let init_instruction = init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![init_instruction], vec![])
+        .await
+        .unwrap();
+
+    let now = prg_test_ctx
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let end_timestamp = now + 1_000;
+    let (sol_treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+
+    // Only reserved tickers can be auctioned
+    let mut auctions = vec![];
+    for ticker in ["AUCT", "REFUND"] {
+        let (reserved_ticker_account, _) =
+            ReservedTicker::find_key(&token_registry_program_id, ticker);
+        let (auction_account, _) = Auction::find_key(&token_registry_program_id, ticker);
+        let start_auction_instruction = start_auction(
+            token_registry_program_id,
+            payer,
+            auction_account,
+            reserved_ticker_account,
+            token_registry::instruction::start_auction::Params {
+                signer_index: 0,
+                ticker: ticker.to_string(),
+                end_timestamp,
+                min_bid: 1_000_000,
+            },
+        );
+        assert!(sign_send_instructions(
+            &mut prg_test_ctx,
+            vec![start_auction_instruction.clone()],
+            vec![]
+        )
+        .await
+        .is_err());
+
+        let reserve_instruction = reserve(
+            token_registry_program_id,
+            payer,
+            reserved_ticker_account,
+            token_registry::instruction::reserve::Params {
+                signer_index: 0,
+                ticker: ticker.to_string(),
+            },
+        );
+        prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+        sign_send_instructions(
+            &mut prg_test_ctx,
+            vec![reserve_instruction, start_auction_instruction],
+            vec![],
+        )
+        .await
+        .unwrap();
+        auctions.push((auction_account, reserved_ticker_account));
+    }
+    let (auction_account, reserved_ticker_account) = auctions[0];
+    let (refund_auction_account, _) = auctions[1];
+
+    // Tickers under auction cannot be registered, even once released
+    let release_instruction = release(
+        token_registry_program_id,
+        payer,
+        auctions[1].1,
+        token_registry::instruction::release::Params {
+            signer_index: 0,
+            ticker: "REFUND".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![release_instruction], vec![])
+        .await
+        .unwrap();
+    let register_instruction = register(
+        token_registry_program_id,
+        payer,
+        other_mint,
+        system_program::ID,
+        payer,
+        None,
+        token_registry::instruction::register::Params {
+            signer_index: Some(0),
+            name: "Refund".to_string(),
+            ticker: "REFUND".to_string(),
+            mint: other_mint.to_string(),
+            decimals: 6,
+            website: "".to_string(),
+            logo_uri: "".to_string(),
+            update_authority: None,
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![register_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    let place_bid = |bidder: &Keypair, previous_bidder: Pubkey, auction: Pubkey, amount: u64| {
+        bid(
+            token_registry_program_id,
+            bidder.pubkey(),
+            auction,
+            previous_bidder,
+            token_registry::instruction::bid::Params { amount },
+        )
+    };
+
+    // Bids below the minimum bid are rejected and outbid bidders are refunded
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![place_bid(&outbid, outbid.pubkey(), auction_account, 1)],
+        vec![&outbid]
+    )
+    .await
+    .is_err());
+    let outbid_balance = prg_test_ctx
+        .banks_client
+        .get_balance(outbid.pubkey())
+        .await
+        .unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            place_bid(&outbid, outbid.pubkey(), auction_account, 1_000_000),
+            place_bid(&outbid, outbid.pubkey(), refund_auction_account, 2_000_000),
+        ],
+        vec![&outbid],
+    )
+    .await
+    .unwrap();
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![place_bid(
+            &winner,
+            outbid.pubkey(),
+            auction_account,
+            1_000_000
+        )],
+        vec![&winner]
+    )
+    .await
+    .is_err());
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![place_bid(
+            &winner,
+            outbid.pubkey(),
+            auction_account,
+            3_000_000,
+        )],
+        vec![&winner],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(outbid.pubkey())
+            .await
+            .unwrap(),
+        outbid_balance - 2_000_000
+    );
+    let auction_data = prg_test_ctx
+        .banks_client
+        .get_account(auction_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let auction = Auction::deserialize(&mut &auction_data[..]).unwrap();
+    assert_eq!(auction.highest_bid, 3_000_000);
+    assert_eq!(auction.highest_bidder, winner.pubkey().to_bytes());
+
+    let settle_instruction = |mint: &Pubkey| {
+        settle_auction(
+            token_registry_program_id,
+            winner.pubkey(),
+            derive_ticker_account(&token_registry_program_id, "AUCT"),
+            derive_mint_account(&token_registry_program_id, mint),
+            Pubkey::from_str(TOKEN_TLD).unwrap(),
+            state_key,
+            auction_account,
+            reserved_ticker_account,
+            DeniedMint::find_key(&token_registry_program_id, mint).0,
+            sol_treasury,
+            *mint,
+            derive_legacy_ticker_account("AUCT"),
+            derive_legacy_mint_account(mint),
+            BondedListing::find_key(&token_registry_program_id, "AUCT").0,
+            token_registry::instruction::settle_auction::Params {
+                name: "Auctioned".to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+            },
+        )
+    };
+
+    // The auction cannot be settled before its end
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![settle_instruction(&winner_mint)],
+        vec![&winner]
+    )
+    .await
+    .is_err());
+    set_unix_timestamp(&mut prg_test_ctx, end_timestamp).await;
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![place_bid(
+            &outbid,
+            winner.pubkey(),
+            auction_account,
+            4_000_000
+        )],
+        vec![&outbid]
+    )
+    .await
+    .is_err());
+
+    // The winning bidder must be the mint authority
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![settle_instruction(&other_mint)],
+        vec![&winner]
+    )
+    .await
+    .is_err());
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![settle_instruction(&winner_mint)],
+        vec![&winner],
+    )
+    .await
+    .unwrap();
+    for closed_account in [auction_account, reserved_ticker_account] {
+        assert!(prg_test_ctx
+            .banks_client
+            .get_account(closed_account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+    let ticker_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_ticker_account(&token_registry_program_id, "AUCT"))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let ticker_record =
+        TickerRecord::from_account_data(&token_registry_program_id, &ticker_data).unwrap();
+    assert_eq!(ticker_record.data.mint, winner_mint.to_bytes());
+
+    // Unsettled auctions are refunded by anyone after the settlement deadline
+    let refund_instruction = refund_auction(
+        token_registry_program_id,
+        refund_auction_account,
+        outbid.pubkey(),
+        token_registry::instruction::refund_auction::Params {},
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![refund_instruction.clone()], vec![])
+            .await
+            .is_err()
+    );
+    set_unix_timestamp(
+        &mut prg_test_ctx,
+        end_timestamp + Auction::SETTLEMENT_PERIOD,
+    )
+    .await;
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![refund_instruction], vec![])
+        .await
+        .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(outbid.pubkey())
+            .await
+            .unwrap(),
+        outbid_balance
+    );
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(refund_auction_account)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_lease() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let mint = Pubkey::new_unique();
+    program_test.add_account(mint, mint_account(COption::None, 6, spl_token::ID));
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+    let (sol_treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+    let ticker_name_account = derive_ticker_account(&token_registry_program_id, "LEASE");
+    let mint_name_account = derive_mint_account(&token_registry_program_id, &mint);
+    let (tombstone_account, _) = Tombstone::find_key(&token_registry_program_id, "LEASE");
+
+    let init_instruction = init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    );
+    let set_lease_terms_instruction = set_lease_terms(
+        token_registry_program_id,
+        payer,
+        state_key,
+        token_registry::instruction::set_lease_terms::Params {
+            signer_index: 0,
+            lease_duration: 1_000,
+            grace_period: 100,
+            ticker_cooldown: 0,
+        },
+    );
+    let register_instruction = register(
+        token_registry_program_id,
+        payer,
+        mint,
+        system_program::ID,
+        payer,
+        None,
+        token_registry::instruction::register::Params {
+            signer_index: Some(0),
+            name: "Leased".to_string(),
+            ticker: "LEASE".to_string(),
+            mint: mint.to_string(),
+            decimals: 6,
+            website: "".to_string(),
+            logo_uri: "".to_string(),
+            update_authority: None,
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            init_instruction,
+            set_lease_terms_instruction,
+            register_instruction,
+        ],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    let get_expiry = |data: Vec<u8>| {
+        TickerRecord::from_account_data(&token_registry_program_id, &data)
+            .unwrap()
+            .data
+            .expiry
+    };
+    let ticker_data = prg_test_ctx
+        .banks_client
+        .get_account(ticker_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let expiry = get_expiry(ticker_data);
+    assert_ne!(expiry, 0);
+
+    // Renewals extend the lease from its current end
+    let renew_instruction = renew(
+        token_registry_program_id,
+        payer,
+        payer,
+        ticker_name_account,
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        sol_treasury,
+        token_registry::instruction::renew::Params {
+            ticker: "LEASE".to_string(),
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![renew_instruction], vec![])
+        .await
+        .unwrap();
+    let ticker_data = prg_test_ctx
+        .banks_client
+        .get_account(ticker_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let expiry = get_expiry(ticker_data);
+
+    // Expired leases no longer resolve
+    let resolve_instruction = resolve_ticker(
+        token_registry_program_id,
+        ticker_name_account,
+        token_registry::instruction::resolve_ticker::Params {
+            ticker: "LEASE".to_string(),
+        },
+    );
+    let return_data = simulate_return_data(&mut prg_test_ctx, resolve_instruction.clone())
+        .await
+        .unwrap();
+    assert_eq!(Mint::try_from_slice(&return_data).unwrap().expiry, expiry);
+    set_unix_timestamp(&mut prg_test_ctx, expiry).await;
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    assert_eq!(
+        simulate_return_data(&mut prg_test_ctx, resolve_instruction)
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenRegistryError::LeaseExpired as u32)
+        )
+    );
+
+    // Tickers are reclaimed after the grace period and their record refunds the treasury
+    let reclaim_instruction = reclaim(
+        token_registry_program_id,
+        payer,
+        ticker_name_account,
+        state_key,
+        tombstone_account,
+        token_registry::instruction::reclaim::Params {
+            ticker: "LEASE".to_string(),
+        },
+    );
+    set_unix_timestamp(&mut prg_test_ctx, expiry + 99).await;
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![reclaim_instruction.clone()], vec![])
+            .await
+            .is_err()
+    );
+
+    let treasury_balance = prg_test_ctx
+        .banks_client
+        .get_balance(sol_treasury)
+        .await
+        .unwrap();
+    let record_balance = prg_test_ctx
+        .banks_client
+        .get_balance(ticker_name_account)
+        .await
+        .unwrap();
+    set_unix_timestamp(&mut prg_test_ctx, expiry + 100).await;
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![reclaim_instruction], vec![])
+        .await
+        .unwrap();
+
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(ticker_name_account)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The mint record is kept
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(mint_name_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.mint, mint.to_bytes());
+    assert_eq!(token_data.ticker, "LEASE");
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(sol_treasury)
+            .await
+            .unwrap(),
+        treasury_balance + record_balance
+    );
+    let tombstone_data = prg_test_ctx
+        .banks_client
+        .get_account(tombstone_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let tombstone = Tombstone::deserialize(&mut &tombstone_data[..]).unwrap();
+    assert_eq!(tombstone.previous_mint, mint.to_bytes());
+}
+
+#[tokio::test]
+async fn test_applications() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let applicant = Keypair::new();
+    program_test.add_account(applicant.pubkey(), funded_account());
+    let mints = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    for mint in mints.iter() {
+        program_test.add_account(*mint, mint_account(COption::None, 6, spl_token::ID));
+    }
+
+    // A ticker deleted at slot 0 that is still in cooldown
+    program_test.add_account(
+        Tombstone::find_key(&token_registry_program_id, "DELETED").0,
+        Account {
+            lamports: 1_000_000_000,
+            data: Tombstone::new(0, Pubkey::new_unique().to_bytes())
+                .try_to_vec()
+                .unwrap(),
+            owner: token_registry_program_id,
+            ..Account::default()
+        },
+    );
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+    let (sol_treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+
+    let registration_fee = 1_000_000_000;
+    let rejection_fee = 10_000_000;
+    let init_instruction = init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    );
+    let set_fee_schedule_instruction = set_fee_schedule(
+        token_registry_program_id,
+        payer,
+        state_key,
+        sol_treasury,
+        token_registry::instruction::set_fee_schedule::Params {
+            signer_index: 0,
+            fee_mint: system_program::ID,
+            registration_fees: [registration_fee; FEE_TIERS],
+            application_deposit: 100_000_000,
+            rejection_fee,
+            report_deposit: 0,
+        },
+    );
+    let set_lease_terms_instruction = set_lease_terms(
+        token_registry_program_id,
+        payer,
+        state_key,
+        token_registry::instruction::set_lease_terms::Params {
+            signer_index: 0,
+            lease_duration: 0,
+            grace_period: 0,
+            ticker_cooldown: 1_000_000,
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            init_instruction,
+            set_fee_schedule_instruction,
+            set_lease_terms_instruction,
+        ],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    let application_key = |ticker: &str| {
+        Application::find_key(&token_registry_program_id, ticker, &applicant.pubkey()).0
+    };
+    let submit_instruction = |ticker: &str, mint: &Pubkey| {
+        submit_application(
+            token_registry_program_id,
+            applicant.pubkey(),
+            application_key(ticker),
+            state_key,
+            ReservedTicker::find_key(&token_registry_program_id, ticker).0,
+            DeniedMint::find_key(&token_registry_program_id, mint).0,
+            sol_treasury,
+            applicant.pubkey(),
+            token_registry::instruction::submit_application::Params {
+                signer_index: None,
+                name: "Applied".to_string(),
+                ticker: ticker.to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+                update_authority: None,
+            },
+        )
+    };
+    let approve_instruction = |ticker: &str, mint: &Pubkey| {
+        approve_application(
+            token_registry_program_id,
+            payer,
+            derive_ticker_account(&token_registry_program_id, ticker),
+            derive_mint_account(&token_registry_program_id, mint),
+            Pubkey::from_str(TOKEN_TLD).unwrap(),
+            state_key,
+            application_key(ticker),
+            ReservedTicker::find_key(&token_registry_program_id, ticker).0,
+            DeniedMint::find_key(&token_registry_program_id, mint).0,
+            applicant.pubkey(),
+            *mint,
+            derive_legacy_ticker_account(ticker),
+            derive_legacy_mint_account(mint),
+            Tombstone::find_key(&token_registry_program_id, ticker).0,
+            BondedListing::find_key(&token_registry_program_id, ticker).0,
+            token_registry::instruction::approve_application::Params { signer_index: 0 },
+        )
+    };
+
+    // Applications must use the normalized ticker
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![submit_instruction("apply", &mints[0])],
+        vec![&applicant]
+    )
+    .await
+    .is_err());
+
+    // The registration fee is charged at submission
+    let treasury_balance = prg_test_ctx
+        .banks_client
+        .get_balance(sol_treasury)
+        .await
+        .unwrap();
+    let applicant_balance = prg_test_ctx
+        .banks_client
+        .get_balance(applicant.pubkey())
+        .await
+        .unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            submit_instruction("APPLY", &mints[0]),
+            submit_instruction("REJECT", &mints[1]),
+            submit_instruction("DELETED", &mints[2]),
+        ],
+        vec![&applicant],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(sol_treasury)
+            .await
+            .unwrap(),
+        treasury_balance + 3 * registration_fee
+    );
+
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![approve_instruction("APPLY", &mints[0])],
+        vec![],
+    )
+    .await
+    .unwrap();
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_mint_account(&token_registry_program_id, &mints[0]))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.ticker, "APPLY");
+    assert_eq!(token_data.tier, VerificationTier::Community);
+
+    // Deleted tickers cannot be approved during their cooldown
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![approve_instruction("DELETED", &mints[2])],
+        vec![]
+    )
+    .await
+    .is_err());
+
+    // Rejections keep the registration fee and the rejection fee, the rest is refunded
+    let reject_instruction = |ticker: &str| {
+        reject_application(
+            token_registry_program_id,
+            payer,
+            state_key,
+            application_key(ticker),
+            sol_treasury,
+            applicant.pubkey(),
+            token_registry::instruction::reject_application::Params { signer_index: 0 },
+        )
+    };
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![reject_instruction("REJECT"), reject_instruction("DELETED")],
+        vec![],
+    )
+    .await
+    .unwrap();
+    for ticker in ["APPLY", "REJECT", "DELETED"] {
+        assert!(prg_test_ctx
+            .banks_client
+            .get_account(application_key(ticker))
+            .await
+            .unwrap()
+            .is_none());
+    }
+    let entry_rent = prg_test_ctx
+        .banks_client
+        .get_balance(derive_ticker_account(&token_registry_program_id, "APPLY"))
+        .await
+        .unwrap()
+        + prg_test_ctx
+            .banks_client
+            .get_balance(derive_mint_account(&token_registry_program_id, &mints[0]))
+            .await
+            .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(applicant.pubkey())
+            .await
+            .unwrap(),
+        applicant_balance - 3 * registration_fee - 2 * rejection_fee - entry_rent
+    );
+}
+
+#[tokio::test]
+async fn test_bonded_listings() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let registrant = Keypair::new();
+    let challenger = Keypair::new();
+    program_test.add_account(registrant.pubkey(), funded_account());
+    program_test.add_account(challenger.pubkey(), funded_account());
+    let tickers = ["BONDED", "CHALLENGED", "CANCELLED"];
+    let mints = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    for mint in mints.iter() {
+        program_test.add_account(*mint, mint_account(COption::None, 6, spl_token::ID));
+    }
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+    let (sol_treasury, _) =
+        CentralState::find_treasury_key(&token_registry_program_id, &system_program::ID);
+    let listing_key = |ticker: &str| BondedListing::find_key(&token_registry_program_id, ticker).0;
+
+    let registration_fee = 1_000_000_000;
+    let bond = 2_000_000_000;
+    let now = prg_test_ctx
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let init_instruction = init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    );
+    let set_fee_schedule_instruction = set_fee_schedule(
+        token_registry_program_id,
+        payer,
+        state_key,
+        sol_treasury,
+        token_registry::instruction::set_fee_schedule::Params {
+            signer_index: 0,
+            fee_mint: system_program::ID,
+            registration_fees: [registration_fee; FEE_TIERS],
+            application_deposit: 0,
+            rejection_fee: 0,
+            report_deposit: 0,
+        },
+    );
+    let set_bond_terms_instruction = set_bond_terms(
+        token_registry_program_id,
+        payer,
+        state_key,
+        token_registry::instruction::set_bond_terms::Params {
+            signer_index: 0,
+            registration_bond: bond,
+            challenge_window: 1_000,
+            arbiter: None,
+        },
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            init_instruction,
+            set_fee_schedule_instruction,
+            set_bond_terms_instruction,
+        ],
+        vec![],
+    )
+    .await
+    .unwrap();
+
+    // The registration fee is charged when listing and the bond is escrowed
+    let treasury_balance = prg_test_ctx
+        .banks_client
+        .get_balance(sol_treasury)
+        .await
+        .unwrap();
+    let registrant_balance = prg_test_ctx
+        .banks_client
+        .get_balance(registrant.pubkey())
+        .await
+        .unwrap();
+    let list_instructions = tickers
+        .iter()
+        .zip(mints.iter())
+        .map(|(ticker, mint)| {
+            list_with_bond(
+                token_registry_program_id,
+                registrant.pubkey(),
+                listing_key(ticker),
+                state_key,
+                ReservedTicker::find_key(&token_registry_program_id, ticker).0,
+                DeniedMint::find_key(&token_registry_program_id, mint).0,
+                sol_treasury,
+                registrant.pubkey(),
+                token_registry::instruction::list_with_bond::Params {
+                    signer_index: None,
+                    name: "Bonded".to_string(),
+                    ticker: ticker.to_string(),
+                    mint: mint.to_string(),
+                    decimals: 6,
+                    website: "".to_string(),
+                    logo_uri: "".to_string(),
+                    update_authority: None,
+                },
+            )
+        })
+        .collect();
+    sign_send_instructions(&mut prg_test_ctx, list_instructions, vec![&registrant])
+        .await
+        .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(sol_treasury)
+            .await
+            .unwrap(),
+        treasury_balance + 3 * registration_fee
+    );
+
+    // Admins cannot register a ticker with a pending listing
+    let register_instruction = register(
+        token_registry_program_id,
+        payer,
+        mints[2],
+        system_program::ID,
+        payer,
+        None,
+        token_registry::instruction::register::Params {
+            signer_index: Some(0),
+            name: "Admin".to_string(),
+            ticker: tickers[0].to_string(),
+            mint: mints[2].to_string(),
+            decimals: 6,
+            website: "".to_string(),
+            logo_uri: "".to_string(),
+            update_authority: None,
+        },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![register_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    // Nor approve an application for it
+    let application_key = Application::find_key(&token_registry_program_id, tickers[0], &payer).0;
+    let submit_instruction = submit_application(
+        token_registry_program_id,
+        payer,
+        application_key,
+        state_key,
+        ReservedTicker::find_key(&token_registry_program_id, tickers[0]).0,
+        DeniedMint::find_key(&token_registry_program_id, &mints[2]).0,
+        sol_treasury,
+        payer,
+        token_registry::instruction::submit_application::Params {
+            signer_index: None,
+            name: "Applied".to_string(),
+            ticker: tickers[0].to_string(),
+            mint: mints[2].to_string(),
+            decimals: 6,
+            website: "".to_string(),
+            logo_uri: "".to_string(),
+            update_authority: None,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![submit_instruction], vec![])
+        .await
+        .unwrap();
+    let approve_instruction = approve_application(
+        token_registry_program_id,
+        payer,
+        derive_ticker_account(&token_registry_program_id, tickers[0]),
+        derive_mint_account(&token_registry_program_id, &mints[2]),
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        application_key,
+        ReservedTicker::find_key(&token_registry_program_id, tickers[0]).0,
+        DeniedMint::find_key(&token_registry_program_id, &mints[2]).0,
+        payer,
+        mints[2],
+        derive_legacy_ticker_account(tickers[0]),
+        derive_legacy_mint_account(&mints[2]),
+        Tombstone::find_key(&token_registry_program_id, tickers[0]).0,
+        listing_key(tickers[0]),
+        token_registry::instruction::approve_application::Params { signer_index: 0 },
+    );
+    assert!(
+        sign_send_instructions(&mut prg_test_ctx, vec![approve_instruction], vec![])
+            .await
+            .is_err()
+    );
+
+    let challenge_instruction = challenge(
+        token_registry_program_id,
+        challenger.pubkey(),
+        listing_key(tickers[1]),
+        token_registry::instruction::challenge::Params {},
+    );
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![challenge_instruction],
+        vec![&challenger],
+    )
+    .await
+    .unwrap();
+
+    // Only unchallenged listings can be cancelled, the bond and the rent are refunded
+    let cancel_instruction = |ticker: &str| {
+        cancel_listing(
+            token_registry_program_id,
+            registrant.pubkey(),
+            listing_key(ticker),
+            token_registry::instruction::cancel_listing::Params {},
+        )
+    };
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![cancel_instruction(tickers[1])],
+        vec![&registrant]
+    )
+    .await
+    .is_err());
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![cancel_instruction(tickers[2])],
+        vec![&registrant],
+    )
+    .await
+    .unwrap();
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(listing_key(tickers[2]))
+        .await
+        .unwrap()
+        .is_none());
+
+    // Listings are finalized once the challenge window is over
+    let finalize_instruction = finalize_listing(
+        token_registry_program_id,
+        payer,
+        derive_ticker_account(&token_registry_program_id, tickers[0]),
+        derive_mint_account(&token_registry_program_id, &mints[0]),
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        listing_key(tickers[0]),
+        ReservedTicker::find_key(&token_registry_program_id, tickers[0]).0,
+        DeniedMint::find_key(&token_registry_program_id, &mints[0]).0,
+        Tombstone::find_key(&token_registry_program_id, tickers[0]).0,
+        registrant.pubkey(),
+        mints[0],
+        derive_legacy_ticker_account(tickers[0]),
+        derive_legacy_mint_account(&mints[0]),
+        token_registry::instruction::finalize_listing::Params {},
+    );
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![finalize_instruction.clone()],
+        vec![]
+    )
+    .await
+    .is_err());
+    set_unix_timestamp(&mut prg_test_ctx, now + 10_000).await;
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(&mut prg_test_ctx, vec![finalize_instruction], vec![])
+        .await
+        .unwrap();
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_mint_account(&token_registry_program_id, &mints[0]))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.ticker, tickers[0]);
+    assert_eq!(token_data.tier, VerificationTier::Community);
+
+    // Upheld challenges slash the bond of the registrant
+    let challenger_balance = prg_test_ctx
+        .banks_client
+        .get_balance(challenger.pubkey())
+        .await
+        .unwrap();
+    let resolve_instruction = resolve_challenge(
+        token_registry_program_id,
+        payer,
+        state_key,
+        listing_key(tickers[1]),
+        registrant.pubkey(),
+        challenger.pubkey(),
+        token_registry::instruction::resolve_challenge::Params {
+            signer_index: Some(0),
+            uphold_listing: false,
+        },
+    );
+    sign_send_instructions(&mut prg_test_ctx, vec![resolve_instruction], vec![])
+        .await
+        .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(challenger.pubkey())
+            .await
+            .unwrap(),
+        challenger_balance + 2 * bond
+    );
+
+    // The registrant paid the fees and lost the slashed bond, the rest was refunded
+    let entry_rent = prg_test_ctx
+        .banks_client
+        .get_balance(derive_ticker_account(
+            &token_registry_program_id,
+            tickers[0],
+        ))
+        .await
+        .unwrap()
+        + prg_test_ctx
+            .banks_client
+            .get_balance(derive_mint_account(&token_registry_program_id, &mints[0]))
+            .await
+            .unwrap();
+    assert_eq!(
+        prg_test_ctx
+            .banks_client
+            .get_balance(registrant.pubkey())
+            .await
+            .unwrap(),
+        registrant_balance - 3 * registration_fee - bond - entry_rent
+    );
+}
+
+#[tokio::test]
+async fn test_claim_entry() {
+    let token_registry_program_id = Pubkey::new_unique();
+    let (state_key, nonce) = derive_central_state(&token_registry_program_id);
+    let mut program_test = registry_program_test(token_registry_program_id, state_key);
+
+    let claimant = Keypair::new();
+    program_test.add_account(claimant.pubkey(), funded_account());
+    let tickers = ["CLAIM", "COMMUNITY"];
+    let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+    for mint in mints.iter() {
+        program_test.add_account(*mint, mint_account(COption::None, 6, spl_token::ID));
+    }
+
+    let mut prg_test_ctx = program_test.start_with_context().await;
+    let payer = prg_test_ctx.payer.pubkey();
+
+    let mut instructions = vec![init(
+        token_registry_program_id,
+        state_key,
+        payer,
+        token_registry::instruction::init::Params {
+            signer_nonce: nonce,
+        },
+    )];
+    for (ticker, mint) in tickers.iter().zip(mints.iter()) {
+        instructions.push(register(
+            token_registry_program_id,
+            payer,
+            *mint,
+            system_program::ID,
+            payer,
+            None,
+            token_registry::instruction::register::Params {
+                signer_index: Some(0),
+                name: "Claimable".to_string(),
+                ticker: ticker.to_string(),
+                mint: mint.to_string(),
+                decimals: 6,
+                website: "".to_string(),
+                logo_uri: "".to_string(),
+                update_authority: None,
+            },
+        ));
+    }
+    instructions.push(set_verification_tier(
+        token_registry_program_id,
+        payer,
+        derive_mint_account(&token_registry_program_id, &mints[1]),
+        Pubkey::from_str(TOKEN_TLD).unwrap(),
+        state_key,
+        token_registry::instruction::set_verification_tier::Params {
+            signer_index: 0,
+            mint: mints[1].to_string(),
+            tier: VerificationTier::Community,
+        },
+    ));
+    sign_send_instructions(&mut prg_test_ctx, instructions, vec![])
+        .await
+        .unwrap();
+
+    let approval_key = |mint: &Pubkey| ClaimApproval::find_key(&token_registry_program_id, mint).0;
+    let approve_instruction = |mint: &Pubkey| {
+        approve_claimant(
+            token_registry_program_id,
+            payer,
+            approval_key(mint),
+            token_registry::instruction::approve_claimant::Params {
+                signer_index: 0,
+                mint: *mint,
+                claimant: Some(claimant.pubkey()),
+            },
+        )
+    };
+    let claim_instruction = |ticker: &str, mint: &Pubkey| {
+        claim_entry(
+            token_registry_program_id,
+            claimant.pubkey(),
+            derive_mint_account(&token_registry_program_id, mint),
+            Pubkey::from_str(TOKEN_TLD).unwrap(),
+            state_key,
+            *mint,
+            approval_key(mint),
+            BondedListing::find_key(&token_registry_program_id, ticker).0,
+            token_registry::instruction::claim_entry::Params {
+                mint: mint.to_string(),
+            },
+        )
+    };
+
+    // Without an approval only the mint authority can claim
+    assert!(sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![claim_instruction(tickers[0], &mints[0])],
+        vec![&claimant]
+    )
+    .await
+    .is_err());
+
+    prg_test_ctx.warp_to_slot(100).unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![
+            approve_instruction(&mints[0]),
+            approve_instruction(&mints[1]),
+        ],
+        vec![],
+    )
+    .await
+    .unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![claim_instruction(tickers[0], &mints[0])],
+        vec![&claimant],
+    )
+    .await
+    .unwrap();
+    let mint_data = prg_test_ctx
+        .banks_client
+        .get_account(derive_mint_account(&token_registry_program_id, &mints[0]))
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let token_data = TokenRecord::from_account_data(&token_registry_program_id, &mint_data)
+        .unwrap()
+        .data;
+    assert_eq!(token_data.update_authority, claimant.pubkey().to_bytes());
+    assert_ne!(token_data.claim_slot, 0);
+    assert!(prg_test_ctx
+        .banks_client
+        .get_account(approval_key(&mints[0]))
+        .await
+        .unwrap()
+        .is_none());
+
+    // Entries are claimed once and only when verified
+    prg_test_ctx.get_new_latest_blockhash().await.unwrap();
+    sign_send_instructions(
+        &mut prg_test_ctx,
+        vec![approve_instruction(&mints[0])],
+        vec![],
+    )
+    .await
+    .unwrap();
+    for (ticker, mint) in tickers.iter().zip(mints.iter()) {
+        assert!(sign_send_instructions(
+            &mut prg_test_ctx,
+            vec![claim_instruction(ticker, mint)],
+            vec![&claimant]
+        )
+        .await
+        .is_err());
+    }
 }